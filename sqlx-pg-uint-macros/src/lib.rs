@@ -1,212 +1,1057 @@
+// Invalid input is reported through `syn::Error`, never by panicking.
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, parse_quote, Data, DeriveInput, Generics, Lifetime, Token, Type,
+    WherePredicate,
+};
 
-#[proc_macro_derive(UIntWrapper)]
+#[proc_macro_derive(UIntWrapper, attributes(uint_wrapper))]
 /// Derive macro for unsigned integer types.
 ///
-/// Derives all the mathematical operations for the unsigned integer type, as well as `Display`,
-/// `From` and `TryFrom` implementations for/to `BigDecimal`, a `to_uint` method to convert the
-/// `PgUint` type to the underlying integer type and a `new` method to create a new `PgUint` type
-/// from the underlying integer type.
+/// Derives all the mathematical operations for the unsigned integer type, as well as `From` and
+/// `TryFrom` implementations for/to `BigDecimal`, a `to_uint` method to convert the `PgUint` type
+/// to the underlying integer type and a `new` method to create a new `PgUint` type from the
+/// underlying integer type. The `checked_*`, `wrapping_*`, `saturating_*` and
+/// `overflowing_*` arithmetic methods of the underlying integer type are mirrored as well, next to
+/// its bit and number-theory methods such as `pow`, `isqrt`, `ilog2`, `count_ones` and `midpoint`.
+///
+/// `Display`, `Debug` and the `std::fmt` radix and exponent traits forward to the underlying
+/// integer type, so formatter flags such as width, fill and `#` behave as they do for it.
+///
+/// Arithmetic and bitwise operators are implemented for owned values as well as references, and
+/// between the `PgUint` type and its underlying integer type in both directions. The same goes for
+/// comparisons with the underlying integer type. `Sum` and `Product` are implemented for owned and
+/// borrowed values, next to the `checked_sum` and `checked_product` helpers.
+///
+/// With the `num-traits` feature enabled, the relevant `num_traits` traits are implemented as well.
+///
+/// Structs which are generic over their underlying integer type get generic implementations.
+/// Implementations which can only be written for a concrete integer type, such as
+/// `From<PgUint<u64>> for u64`, are generated for every type listed in the `#[uint_wrapper(...)]`
+/// attribute, as are `PartialEq` and `PartialOrd` between every pair of those types, which compare
+/// the numeric values. The types are listed from the narrowest to the widest, and the attribute may
+/// be repeated, for example behind `cfg_attr`.
 pub fn uint_wrapper_derive(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
+    wrapper_derive(&input, false)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[proc_macro_derive(IntWrapper)]
+/// Derive macro for signed integer types.
+///
+/// Derives the same implementations as `UIntWrapper`, with a `to_int` method in place of `to_uint`.
+/// Additionally, `Neg` is implemented, and `num_traits::Signed` takes the place of
+/// `num_traits::Unsigned`. The bit and number-theory methods of the unsigned types, such as `pow`
+/// and `isqrt`, are not generated.
+pub fn int_wrapper_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    wrapper_derive(&input, true)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Generates the implementations shared by `UIntWrapper` and `IntWrapper`.
+fn wrapper_derive(input: &DeriveInput, signed: bool) -> syn::Result<TokenStream2> {
     let name = &input.ident;
+    let uint = inner_type(input)?;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let ty = quote!(#name #ty_generics);
+    // Operations on the underlying integer go through the crate's `Integer` trait, so that they
+    // can be used in generic implementations.
+    let int = quote!(<#uint as crate::private::Integer>);
 
-    let gen = quote! {
-        impl std::fmt::Display for #name {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(f, "{}", self.to_uint())
+    // Implementations with the underlying integer type as the implementing type cannot be generic
+    // over it, so they are generated for every concrete type the derive is used for.
+    let concrete_types = if input.generics.type_params().next().is_some() {
+        attribute_types(input)?
+            .into_iter()
+            .map(|uint| (quote!(#name<#uint>), uint))
+            .collect::<Vec<_>>()
+    } else {
+        vec![(quote!(#name), uint.clone())]
+    };
+
+    let generics_a = with_lifetime(&input.generics, parse_quote!('a));
+    let (impl_generics_a, _, _) = generics_a.split_for_impl();
+    let generics_q = with_lifetime(&input.generics, parse_quote!('q));
+    let (impl_generics_q, _, _) = generics_q.split_for_impl();
+    let generics_r = with_lifetime(&input.generics, parse_quote!('r));
+    let (impl_generics_r, _, _) = generics_r.split_for_impl();
+    let generics_de = with_lifetime(&input.generics, parse_quote!('de));
+    let (impl_generics_de, _, _) = generics_de.split_for_impl();
+    let generics_to_primitive = with_predicate(
+        &input.generics,
+        parse_quote!(#uint: num_traits::ToPrimitive),
+    );
+    let (impl_generics_to_primitive, _, where_clause_to_primitive) =
+        generics_to_primitive.split_for_impl();
+    let generics_from_primitive = with_predicate(
+        &input.generics,
+        parse_quote!(#uint: num_traits::FromPrimitive),
+    );
+    let (impl_generics_from_primitive, _, where_clause_from_primitive) =
+        generics_from_primitive.split_for_impl();
+
+    let to_prim = if signed {
+        format_ident!("to_int")
+    } else {
+        format_ident!("to_uint")
+    };
+    let try_to_prim = format_ident!("try_{}", to_prim);
+
+    // `U256` does not implement the exponent traits, so they go through the `Integer` trait.
+    let fmt_traits = [
+        ("Display", quote!(std::fmt::Display::fmt)),
+        ("LowerHex", quote!(std::fmt::LowerHex::fmt)),
+        ("UpperHex", quote!(std::fmt::UpperHex::fmt)),
+        ("Octal", quote!(std::fmt::Octal::fmt)),
+        ("Binary", quote!(std::fmt::Binary::fmt)),
+        ("LowerExp", quote!(#int::fmt_lower_exp)),
+        ("UpperExp", quote!(#int::fmt_upper_exp)),
+    ]
+    .into_iter()
+    .map(|(trait_name, fmt)| {
+        let fmt_trait = format_ident!("{}", trait_name);
+        quote! {
+            impl #impl_generics std::fmt::#fmt_trait for #ty #where_clause {
+                fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                    #fmt(&self.inner, f)
+                }
             }
         }
+    });
 
-        impl std::ops::Add for #name {
-            type Output = Self;
+    let binary_ops = [
+        ("Add", "add", quote!(+)),
+        ("Sub", "sub", quote!(-)),
+        ("Mul", "mul", quote!(*)),
+        ("Div", "div", quote!(/)),
+        ("Rem", "rem", quote!(%)),
+        ("BitAnd", "bitand", quote!(&)),
+        ("BitOr", "bitor", quote!(|)),
+        ("BitXor", "bitxor", quote!(^)),
+    ]
+    .into_iter()
+    .map(|(trait_name, method, op)| {
+        let op_trait = format_ident!("{}", trait_name);
+        let op_method = format_ident!("{}", method);
+        let assign_trait = format_ident!("{}Assign", trait_name);
+        let assign_method = format_ident!("{}_assign", method);
+        let primitive_lhs = concrete_types.iter().map(|(concrete, uint)| {
+            quote! {
+                impl std::ops::#op_trait<#concrete> for #uint {
+                    type Output = #concrete;
 
-            fn add(self, rhs: Self) -> Self::Output {
-                Self::new(self.to_uint() + rhs.to_uint())
+                    fn #op_method(self, rhs: #concrete) -> Self::Output {
+                        <#concrete>::new(self #op rhs.inner)
+                    }
+                }
             }
+        });
+        quote! {
+            impl #impl_generics std::ops::#op_trait for #ty #where_clause {
+                type Output = Self;
+
+                fn #op_method(self, rhs: Self) -> Self::Output {
+                    Self::new(self.inner #op rhs.inner)
+                }
+            }
+
+            impl #impl_generics std::ops::#op_trait<&#ty> for #ty #where_clause {
+                type Output = #ty;
+
+                fn #op_method(self, rhs: &#ty) -> Self::Output {
+                    self #op *rhs
+                }
+            }
+
+            impl #impl_generics std::ops::#op_trait<#ty> for &#ty #where_clause {
+                type Output = #ty;
+
+                fn #op_method(self, rhs: #ty) -> Self::Output {
+                    *self #op rhs
+                }
+            }
+
+            impl #impl_generics std::ops::#op_trait<&#ty> for &#ty #where_clause {
+                type Output = #ty;
+
+                fn #op_method(self, rhs: &#ty) -> Self::Output {
+                    *self #op *rhs
+                }
+            }
+
+            impl #impl_generics std::ops::#assign_trait for #ty #where_clause {
+                fn #assign_method(&mut self, rhs: Self) {
+                    *self = *self #op rhs;
+                }
+            }
+
+            impl #impl_generics std::ops::#assign_trait<&#ty> for #ty #where_clause {
+                fn #assign_method(&mut self, rhs: &#ty) {
+                    *self = *self #op *rhs;
+                }
+            }
+
+            impl #impl_generics std::ops::#op_trait<#uint> for #ty #where_clause {
+                type Output = Self;
+
+                fn #op_method(self, rhs: #uint) -> Self::Output {
+                    Self::new(self.inner #op rhs)
+                }
+            }
+
+            impl #impl_generics std::ops::#assign_trait<#uint> for #ty #where_clause {
+                fn #assign_method(&mut self, rhs: #uint) {
+                    *self = *self #op rhs;
+                }
+            }
+
+            #(#primitive_lhs)*
         }
+    });
 
-        impl std::ops::Mul for #name {
-            type Output = Self;
+    let shift_ops = [("Shl", "shl", quote!(<<)), ("Shr", "shr", quote!(>>))]
+        .into_iter()
+        .map(|(trait_name, method, op)| {
+            let op_trait = format_ident!("{}", trait_name);
+            let op_method = format_ident!("{}", method);
+            let assign_trait = format_ident!("{}Assign", trait_name);
+            let assign_method = format_ident!("{}_assign", method);
+            quote! {
+                impl #impl_generics std::ops::#op_trait<u32> for #ty #where_clause {
+                    type Output = Self;
 
-            fn mul(self, rhs: Self) -> Self::Output {
-                Self::new(self.to_uint() * rhs.to_uint())
+                    fn #op_method(self, rhs: u32) -> Self::Output {
+                        Self::new(self.inner #op rhs)
+                    }
+                }
+
+                impl #impl_generics std::ops::#op_trait<u32> for &#ty #where_clause {
+                    type Output = #ty;
+
+                    fn #op_method(self, rhs: u32) -> Self::Output {
+                        *self #op rhs
+                    }
+                }
+
+                impl #impl_generics std::ops::#assign_trait<u32> for #ty #where_clause {
+                    fn #assign_method(&mut self, rhs: u32) {
+                        *self = *self #op rhs;
+                    }
+                }
+            }
+        });
+
+    let num_traits_ops = [
+        ("CheckedAdd", "checked_add", quote!(Option<Self>)),
+        ("CheckedSub", "checked_sub", quote!(Option<Self>)),
+        ("CheckedMul", "checked_mul", quote!(Option<Self>)),
+        ("CheckedDiv", "checked_div", quote!(Option<Self>)),
+        ("CheckedRem", "checked_rem", quote!(Option<Self>)),
+        ("WrappingAdd", "wrapping_add", quote!(Self)),
+        ("WrappingSub", "wrapping_sub", quote!(Self)),
+        ("WrappingMul", "wrapping_mul", quote!(Self)),
+        ("SaturatingAdd", "saturating_add", quote!(Self)),
+        ("SaturatingSub", "saturating_sub", quote!(Self)),
+        ("SaturatingMul", "saturating_mul", quote!(Self)),
+    ]
+    .into_iter()
+    .map(|(trait_name, method, output)| {
+        let op_trait = format_ident!("{}", trait_name);
+        let op_method = format_ident!("{}", method);
+        quote! {
+            #[cfg(feature = "num-traits")]
+            impl #impl_generics num_traits::#op_trait for #ty #where_clause {
+                fn #op_method(&self, v: &Self) -> #output {
+                    Self::#op_method(*self, *v)
+                }
             }
         }
+    });
 
-        impl std::ops::Sub for #name {
-            type Output = Self;
+    let primitive_impls = concrete_types.iter().map(|(concrete, uint)| {
+        quote! {
+            impl From<#concrete> for #uint {
+                fn from(value: #concrete) -> Self {
+                    value.inner
+                }
+            }
 
-            fn sub(self, rhs: Self) -> Self::Output {
-                Self::new(self.to_uint() - rhs.to_uint())
+            impl PartialEq<#concrete> for #uint {
+                fn eq(&self, other: &#concrete) -> bool {
+                    *self == other.inner
+                }
+            }
+
+            impl PartialOrd<#concrete> for #uint {
+                fn partial_cmp(&self, other: &#concrete) -> Option<std::cmp::Ordering> {
+                    self.partial_cmp(&other.inner)
+                }
             }
         }
+    });
 
-        impl std::ops::Div for #name {
-            type Output = Self;
+    // Comparisons between every pair of concrete types, such as `PgUint<u32>` and `PgUint<u64>`.
+    // The types are listed from the narrowest to the widest, and the narrower side is widened to
+    // the type of the other.
+    let cross_width_impls = concrete_types
+        .iter()
+        .enumerate()
+        .flat_map(|(i, (lhs, lhs_uint))| {
+            concrete_types
+                .iter()
+                .enumerate()
+                .filter(move |(j, _)| i != *j)
+                .map(move |(j, (rhs, rhs_uint))| {
+                    let (lhs_value, rhs_value) = if i < j {
+                        (quote!(<#rhs_uint>::from(self.inner)), quote!(other.inner))
+                    } else {
+                        (quote!(self.inner), quote!(<#lhs_uint>::from(other.inner)))
+                    };
+                    quote! {
+                        impl PartialEq<#rhs> for #lhs {
+                            fn eq(&self, other: &#rhs) -> bool {
+                                #lhs_value == #rhs_value
+                            }
+                        }
+
+                        impl PartialOrd<#rhs> for #lhs {
+                            fn partial_cmp(&self, other: &#rhs) -> Option<std::cmp::Ordering> {
+                                #lhs_value.partial_cmp(&#rhs_value)
+                            }
+                        }
+                    }
+                })
+        });
+
+    // `to_option_uint` and `OptionPgUint` only exist for unsigned types.
+    let option_impls = if signed {
+        quote!()
+    } else {
+        quote! {
+            impl #impl_generics #ty #where_clause {
+                /// Converts `Option<PgUint>` to `Option<[underlying integer type]>`.
+                #[deprecated(
+                    note = "use `Some(value)` or `to_uint` instead, or `IntoUints` for containers"
+                )]
+                pub fn to_option_uint(&self) -> Option<#uint> {
+                    <Option<#ty> as OptionPgUint<#uint>>::to_option_uint(&Some(*self))
+                }
+            }
+
+            impl #impl_generics OptionPgUint<#uint> for Option<#ty> #where_clause {
+                fn to_option_uint(&self) -> Option<#uint> {
+                    self.map(|v| v.to_uint())
+                }
+            }
+        }
+    };
+
+    // Bit and number-theory methods of the unsigned primitive integer types.
+    let unsigned_methods = if signed {
+        quote!()
+    } else {
+        let uint_ops = quote!(<#uint as crate::private::Unsigned>);
+        quote! {
+            impl #impl_generics #ty #where_clause {
+                /// Raises `self` to the power of `exp`, using exponentiation by squaring. Panics
+                /// on overflow in debug builds.
+                pub fn pow(self, exp: u32) -> Self {
+                    Self::new(#uint_ops::pow(self.inner, exp))
+                }
+
+                /// Checked exponentiation. Returns `None` on overflow.
+                pub fn checked_pow(self, exp: u32) -> Option<Self> {
+                    #uint_ops::checked_pow(self.inner, exp).map(Self::new)
+                }
+
+                /// Returns the square root of `self`, rounded down.
+                pub fn isqrt(self) -> Self {
+                    Self::new(#uint_ops::isqrt(self.inner))
+                }
+
+                /// Returns the base 2 logarithm of `self`, rounded down. Panics if `self` is zero.
+                pub fn ilog2(self) -> u32 {
+                    #uint_ops::ilog2(self.inner)
+                }
+
+                /// Returns the base 10 logarithm of `self`, rounded down. Panics if `self` is zero.
+                pub fn ilog10(self) -> u32 {
+                    #uint_ops::ilog10(self.inner)
+                }
+
+                /// Returns the number of ones in the binary representation of `self`.
+                pub fn count_ones(self) -> u32 {
+                    #uint_ops::count_ones(self.inner)
+                }
+
+                /// Returns the number of leading zeros in the binary representation of `self`.
+                pub fn leading_zeros(self) -> u32 {
+                    #uint_ops::leading_zeros(self.inner)
+                }
+
+                /// Returns the number of trailing zeros in the binary representation of `self`.
+                pub fn trailing_zeros(self) -> u32 {
+                    #uint_ops::trailing_zeros(self.inner)
+                }
+
+                /// Shifts the bits to the left by `n`, wrapping the truncated bits to the end.
+                pub fn rotate_left(self, n: u32) -> Self {
+                    Self::new(#uint_ops::rotate_left(self.inner, n))
+                }
+
+                /// Shifts the bits to the right by `n`, wrapping the truncated bits to the
+                /// beginning.
+                pub fn rotate_right(self, n: u32) -> Self {
+                    Self::new(#uint_ops::rotate_right(self.inner, n))
+                }
+
+                /// Reverses the byte order of the integer.
+                pub fn swap_bytes(self) -> Self {
+                    Self::new(#uint_ops::swap_bytes(self.inner))
+                }
+
+                /// Returns `true` if `self` is a power of two.
+                pub fn is_power_of_two(self) -> bool {
+                    #uint_ops::is_power_of_two(self.inner)
+                }
+
+                /// Returns the smallest power of two greater than or equal to `self`. Panics on
+                /// overflow in debug builds.
+                pub fn next_power_of_two(self) -> Self {
+                    Self::new(#uint_ops::next_power_of_two(self.inner))
+                }
+
+                /// Divides `self` by `rhs`, rounding the result up. Panics if `rhs` is zero.
+                pub fn div_ceil(self, rhs: Self) -> Self {
+                    Self::new(#uint_ops::div_ceil(self.inner, rhs.inner))
+                }
 
-            fn div(self, rhs: Self) -> Self::Output {
-                Self::new(self.to_uint() / rhs.to_uint())
+                /// Returns the absolute difference between `self` and `other`.
+                pub fn abs_diff(self, other: Self) -> Self {
+                    Self::new(#uint_ops::abs_diff(self.inner, other.inner))
+                }
+
+                /// Returns the average of `self` and `rhs`, rounded down, without overflowing.
+                pub fn midpoint(self, rhs: Self) -> Self {
+                    Self::new(#uint_ops::midpoint(self.inner, rhs.inner))
+                }
             }
         }
+    };
+
+    let sign_impls = if signed {
+        quote! {
+            impl #impl_generics std::ops::Neg for #ty #where_clause {
+                type Output = Self;
+
+                fn neg(self) -> Self::Output {
+                    Self::new(-self.inner)
+                }
+            }
+
+            impl #impl_generics std::ops::Neg for &#ty #where_clause {
+                type Output = #ty;
+
+                fn neg(self) -> Self::Output {
+                    -*self
+                }
+            }
+
+            #[cfg(feature = "num-traits")]
+            impl #impl_generics num_traits::Signed for #ty #where_clause {
+                fn abs(&self) -> Self {
+                    Self::new(self.inner.abs())
+                }
+
+                fn abs_sub(&self, other: &Self) -> Self {
+                    if self.inner <= other.inner {
+                        Self::new(#int::ZERO)
+                    } else {
+                        Self::new(self.inner - other.inner)
+                    }
+                }
+
+                fn signum(&self) -> Self {
+                    Self::new(self.inner.signum())
+                }
+
+                fn is_positive(&self) -> bool {
+                    self.inner.is_positive()
+                }
+
+                fn is_negative(&self) -> bool {
+                    self.inner.is_negative()
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[cfg(feature = "num-traits")]
+            impl #impl_generics num_traits::Unsigned for #ty #where_clause {}
+        }
+    };
+
+    let gen = quote! {
+        #(#fmt_traits)*
 
-        impl std::ops::Rem for #name {
+        impl #impl_generics std::fmt::Debug for #ty #where_clause {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                std::fmt::Debug::fmt(&self.inner, f)
+            }
+        }
+
+        #(#binary_ops)*
+
+        #(#shift_ops)*
+
+        impl #impl_generics std::ops::Not for #ty #where_clause {
             type Output = Self;
 
-            fn rem(self, rhs: Self) -> Self::Output {
-                Self::new(self.to_uint() % rhs.to_uint())
+            fn not(self) -> Self::Output {
+                Self::new(!self.inner)
             }
         }
 
-        impl std::ops::AddAssign for #name {
-            fn add_assign(&mut self, rhs: Self) {
-                let inner = BigDecimal::from(self.to_uint() + rhs.to_uint());
-                *self = Self { inner };
+        impl #impl_generics std::ops::Not for &#ty #where_clause {
+            type Output = #ty;
+
+            fn not(self) -> Self::Output {
+                !*self
             }
         }
 
-        impl std::ops::SubAssign for #name {
-            fn sub_assign(&mut self, rhs: Self) {
-                let inner = BigDecimal::from(self.to_uint() - rhs.to_uint());
-                *self = Self { inner };
+        impl #impl_generics PartialEq<#uint> for #ty #where_clause {
+            fn eq(&self, other: &#uint) -> bool {
+                self.inner == *other
             }
         }
 
-        impl std::ops::MulAssign for #name {
-            fn mul_assign(&mut self, rhs: Self) {
-                let inner = BigDecimal::from(self.to_uint() * rhs.to_uint());
-                *self = Self { inner };
+        impl #impl_generics PartialOrd<#uint> for #ty #where_clause {
+            fn partial_cmp(&self, other: &#uint) -> Option<std::cmp::Ordering> {
+                self.inner.partial_cmp(other)
             }
         }
 
-        impl std::ops::DivAssign for #name {
-            fn div_assign(&mut self, rhs: Self) {
-                let inner = BigDecimal::from(self.to_uint() / rhs.to_uint());
-                *self = Self { inner };
+        #(#primitive_impls)*
+
+        #(#cross_width_impls)*
+
+        impl #impl_generics From<#uint> for #ty #where_clause {
+            fn from(value: #uint) -> Self {
+                Self::new(value)
             }
         }
 
-        impl std::str::FromStr for #name {
+        impl #impl_generics std::iter::Sum for #ty #where_clause {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::new(#int::ZERO), |acc, item| acc + item)
+            }
+        }
+
+        impl #impl_generics_a std::iter::Sum<&'a #ty> for #ty #where_clause {
+            fn sum<I: Iterator<Item = &'a #ty>>(iter: I) -> Self {
+                iter.fold(Self::new(#int::ZERO), |acc, item| acc + item)
+            }
+        }
+
+        impl #impl_generics std::iter::Product for #ty #where_clause {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::new(#int::ONE), |acc, item| acc * item)
+            }
+        }
+
+        impl #impl_generics_a std::iter::Product<&'a #ty> for #ty #where_clause {
+            fn product<I: Iterator<Item = &'a #ty>>(iter: I) -> Self {
+                iter.fold(Self::new(#int::ONE), |acc, item| acc * item)
+            }
+        }
+
+        impl #impl_generics std::str::FromStr for #ty #where_clause {
             type Err = Error;
 
             fn from_str(s: &str) -> Result<Self, Self::Err> {
-                let unsigned_int: <Self as UIntType>::Uint = s.parse()?;
-                Ok(Self::from(unsigned_int))
+                #int::from_str_radix(s, 10)
+                    .map(Self::new)
+                    .map_err(|e| crate::Error::parse(#int::NAME, s, e))
             }
         }
 
-        impl #name {
-            /// Converts this type to the associated unsigned integer type
-            pub fn to_uint(&self) -> <#name as UIntType>::Uint {
-                let stringed_num = self.inner.to_string();
-                stringed_num.parse().unwrap()
+        impl #impl_generics #ty #where_clause {
+            /// The smallest value that can be represented by this type.
+            pub const MIN: Self = Self::new(#int::MIN);
+            /// The largest value that can be represented by this type.
+            pub const MAX: Self = Self::new(#int::MAX);
+            /// The value zero.
+            pub const ZERO: Self = Self::new(#int::ZERO);
+            /// The value one.
+            pub const ONE: Self = Self::new(#int::ONE);
+            /// The size of the associated integer type in bits.
+            pub const BITS: u32 = #int::BITS;
+            /// The precision of the `NUMERIC(p, 0)` column type which fits every value of this
+            /// type.
+            pub const NUMERIC_PRECISION: u32 = #int::NUMERIC_PRECISION;
+
+            /// Converts this type to the associated integer type
+            pub const fn #to_prim(&self) -> #uint {
+                self.inner
+            }
+
+            /// Fallible counterpart of `to_uint`, or `to_int` for signed types. Values are
+            /// validated when they are constructed, so this never returns an error. It is meant
+            /// for code which treats all conversions out of the database types as fallible.
+            pub const fn #try_to_prim(&self) -> Result<#uint, crate::Error> {
+                Ok(self.inner)
+            }
+
+            /// Creates a new instance of this type from the associated integer type
+            pub const fn new(num: #uint) -> Self {
+                Self { inner: num }
+            }
+
+            /// Returns the associated integer type, taking `self` by value
+            pub const fn get(self) -> #uint {
+                self.inner
             }
 
-            /// Converts `Option<PgUint>` to `Option<[underlying integer type]>`.
-            pub fn to_option_uint(&self) -> Option<<#name as UIntType>::Uint> {
-                <Option<#name> as OptionPgUint<#name>>::to_option_uint(&Some(self.clone()))
+            /// Parses a string in the given radix, like the `from_str_radix` method of the
+            /// underlying integer type.
+            ///
+            /// Instead of panicking, a radix outside of `2..=36` fails with
+            /// `IntErrorKind::InvalidDigit`.
+            pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, crate::Error> {
+                #int::from_str_radix(src, radix)
+                    .map(Self::new)
+                    .map_err(|e| crate::Error::parse(#int::NAME, src, e))
             }
 
-            /// Creates a new instance of this type from the associated unsigned integer type
-            pub fn new(num: <#name as UIntType>::Uint) -> Self {
-                Self {
-                    inner: BigDecimal::from(num),
+            /// Parses a string more leniently than `FromStr`.
+            ///
+            /// Surrounding whitespace and `_` separators are ignored, `0x`, `0o` and `0b` prefixes
+            /// select the radix, and decimal numbers may use scientific notation as long as they
+            /// are integral, e.g. `1e6` or `1.5e3`.
+            pub fn parse_lenient(src: &str) -> Result<Self, crate::Error> {
+                let parse_error = |kind| crate::Error::Parse {
+                    type_name: #int::NAME,
+                    input: src.to_owned(),
+                    kind,
+                };
+                match crate::parse::lenient(src).map_err(parse_error)? {
+                    crate::parse::Lenient::Radix(digits, radix) => {
+                        #int::from_str_radix(&digits, radix)
+                            .map(Self::new)
+                            .map_err(|e| crate::Error::parse(#int::NAME, src, e))
+                    }
+                    crate::parse::Lenient::Decimal(value) => Self::try_from(value),
                 }
             }
 
-            /// Returns a shared reference to the inner `BigDecimal` value
-            pub fn as_big_decimal(&self) -> &BigDecimal {
-                &self.inner
+            /// Converts this type to a `BigDecimal`
+            pub fn to_big_decimal(&self) -> BigDecimal {
+                #int::to_big_decimal(self.inner)
+            }
+
+            /// Converts this type to a `BigDecimal`
+            #[deprecated(
+                note = "the value is no longer stored as a `BigDecimal`, use `to_big_decimal` \
+                        instead"
+            )]
+            pub fn as_big_decimal(&self) -> BigDecimal {
+                self.to_big_decimal()
+            }
+            /// Checked integer addition. Returns `None` on overflow.
+            pub fn checked_add(self, rhs: Self) -> Option<Self> {
+                #int::checked_add(self.inner, rhs.inner).map(Self::new)
+            }
+
+            /// Checked integer subtraction. Returns `None` on overflow.
+            pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+                #int::checked_sub(self.inner, rhs.inner).map(Self::new)
+            }
+
+            /// Checked integer multiplication. Returns `None` on overflow.
+            pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+                #int::checked_mul(self.inner, rhs.inner).map(Self::new)
+            }
+
+            /// Checked integer division. Returns `None` on overflow or if `rhs` is zero.
+            pub fn checked_div(self, rhs: Self) -> Option<Self> {
+                #int::checked_div(self.inner, rhs.inner).map(Self::new)
+            }
+
+            /// Checked integer remainder. Returns `None` on overflow or if `rhs` is zero.
+            pub fn checked_rem(self, rhs: Self) -> Option<Self> {
+                #int::checked_rem(self.inner, rhs.inner).map(Self::new)
+            }
+
+            /// Wrapping integer addition. Wraps around at the boundary of the type.
+            pub fn wrapping_add(self, rhs: Self) -> Self {
+                Self::new(#int::wrapping_add(self.inner, rhs.inner))
+            }
+
+            /// Wrapping integer subtraction. Wraps around at the boundary of the type.
+            pub fn wrapping_sub(self, rhs: Self) -> Self {
+                Self::new(#int::wrapping_sub(self.inner, rhs.inner))
+            }
+
+            /// Wrapping integer multiplication. Wraps around at the boundary of the type.
+            pub fn wrapping_mul(self, rhs: Self) -> Self {
+                Self::new(#int::wrapping_mul(self.inner, rhs.inner))
+            }
+
+            /// Wrapping integer division. Wraps around at the boundary of the type. Panics if `rhs`
+            /// is zero.
+            pub fn wrapping_div(self, rhs: Self) -> Self {
+                Self::new(#int::wrapping_div(self.inner, rhs.inner))
+            }
+
+            /// Wrapping integer remainder. Wraps around at the boundary of the type. Panics if
+            /// `rhs` is zero.
+            pub fn wrapping_rem(self, rhs: Self) -> Self {
+                Self::new(#int::wrapping_rem(self.inner, rhs.inner))
+            }
+
+            /// Saturating integer addition. Saturates at the numeric bounds of the type.
+            pub fn saturating_add(self, rhs: Self) -> Self {
+                Self::new(#int::saturating_add(self.inner, rhs.inner))
+            }
+
+            /// Saturating integer subtraction. Saturates at the numeric bounds of the type.
+            pub fn saturating_sub(self, rhs: Self) -> Self {
+                Self::new(#int::saturating_sub(self.inner, rhs.inner))
+            }
+
+            /// Saturating integer multiplication. Saturates at the numeric bounds of the type.
+            pub fn saturating_mul(self, rhs: Self) -> Self {
+                Self::new(#int::saturating_mul(self.inner, rhs.inner))
+            }
+
+            /// Saturating integer division. Saturates at the numeric bounds of the type. Panics if
+            /// `rhs` is zero.
+            pub fn saturating_div(self, rhs: Self) -> Self {
+                Self::new(#int::saturating_div(self.inner, rhs.inner))
+            }
+
+            /// Overflowing integer addition. Returns the wrapped result along with a boolean
+            /// indicating whether an arithmetic overflow occurred.
+            pub fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflowed) = #int::overflowing_add(self.inner, rhs.inner);
+                (Self::new(inner), overflowed)
+            }
+
+            /// Overflowing integer subtraction. Returns the wrapped result along with a boolean
+            /// indicating whether an arithmetic overflow occurred.
+            pub fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflowed) = #int::overflowing_sub(self.inner, rhs.inner);
+                (Self::new(inner), overflowed)
+            }
+
+            /// Overflowing integer multiplication. Returns the wrapped result along with a boolean
+            /// indicating whether an arithmetic overflow occurred.
+            pub fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflowed) = #int::overflowing_mul(self.inner, rhs.inner);
+                (Self::new(inner), overflowed)
+            }
+
+            /// Overflowing integer division. Returns the wrapped result along with a boolean
+            /// indicating whether an arithmetic overflow occurred. Panics if `rhs` is zero.
+            pub fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflowed) = #int::overflowing_div(self.inner, rhs.inner);
+                (Self::new(inner), overflowed)
             }
-        }
 
-        impl OptionPgUint<#name> for Option<#name> where #name: UIntType {
-            fn to_option_uint(&self) -> Option<<#name as UIntType>::Uint> {
-                self.clone().map(|v| v.to_uint())
+            /// Overflowing integer remainder. Returns the wrapped result along with a boolean
+            /// indicating whether an arithmetic overflow occurred. Panics if `rhs` is zero.
+            pub fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+                let (inner, overflowed) = #int::overflowing_rem(self.inner, rhs.inner);
+                (Self::new(inner), overflowed)
+            }
+
+            /// Sums up the values of an iterator, returning `None` if the sum overflows.
+            pub fn checked_sum<I>(iter: I) -> Option<Self>
+            where
+                I: IntoIterator,
+                I::Item: std::borrow::Borrow<Self>,
+            {
+                iter.into_iter().try_fold(Self::new(#int::ZERO), |acc, item| {
+                    acc.checked_add(*std::borrow::Borrow::borrow(&item))
+                })
+            }
+
+            /// Multiplies the values of an iterator, returning `None` if the product overflows.
+            pub fn checked_product<I>(iter: I) -> Option<Self>
+            where
+                I: IntoIterator,
+                I::Item: std::borrow::Borrow<Self>,
+            {
+                iter.into_iter().try_fold(Self::new(#int::ONE), |acc, item| {
+                    acc.checked_mul(*std::borrow::Borrow::borrow(&item))
+                })
             }
         }
 
-        impl TryFrom<BigDecimal> for #name {
+        #option_impls
+
+        #unsigned_methods
+
+        impl #impl_generics TryFrom<BigDecimal> for #ty #where_clause {
             type Error = crate::Error;
 
             fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+                let value = crate::check_magnitude(
+                    #int::NAME,
+                    value,
+                    #int::NUMERIC_PRECISION,
+                    #int::to_big_decimal(#int::MIN),
+                    #int::to_big_decimal(#int::MAX),
+                )?;
                 let value_ref = &value;
                 if !value_ref.is_integer() {
-                    return Err(crate::Error::Fractional(value));
+                    return Err(crate::Error::Fractional {
+                        type_name: #int::NAME,
+                        value,
+                    });
                 }
-                if value_ref.to_string().parse::<u128>().is_err() {
-                    return Err(crate::Error::InvalidValue(value));
+                // Rescaling to zero normalizes integral values such as `5.00` or `1E+3`. Converting
+                // the digits fails if they are out of range for the underlying integer type.
+                let (digits, _) = value_ref.with_scale(0).into_bigint_and_exponent();
+                match #int::try_from_big_int(&digits) {
+                    Some(inner) => Ok(Self { inner }),
+                    None => Err(crate::Error::out_of_range(
+                        #int::NAME,
+                        value,
+                        #int::to_big_decimal(#int::MIN),
+                        #int::to_big_decimal(#int::MAX),
+                    )),
                 }
-                Ok(Self { inner: value })
             }
         }
 
-        impl From<#name> for BigDecimal {
-            fn from(value: #name) -> Self {
-                value.inner
+        impl #impl_generics From<#ty> for BigDecimal #where_clause {
+            fn from(value: #ty) -> Self {
+                value.to_big_decimal()
             }
         }
 
-        impl Default for #name {
+        impl #impl_generics Default for #ty #where_clause {
             fn default() -> Self {
-                Self::from(0)
+                Self::new(#int::ZERO)
             }
         }
 
-        impl sqlx::Type<sqlx::Postgres> for #name {
+        impl #impl_generics sqlx::Type<sqlx::Postgres> for #ty #where_clause {
             fn type_info() -> <sqlx::Postgres as sqlx::Database>::TypeInfo {
                 <BigDecimal as sqlx::Type<sqlx::Postgres>>::type_info()
             }
         }
 
-        impl<'q> sqlx::Encode<'q, sqlx::Postgres> for #name {
+        impl #impl_generics_q sqlx::Encode<'q, sqlx::Postgres> for #ty #where_clause {
             fn encode_by_ref(
                 &self,
                 buf: &mut <sqlx::Postgres as sqlx::Database>::ArgumentBuffer<'q>,
             ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
-                <BigDecimal as sqlx::Encode<sqlx::Postgres>>::encode_by_ref(&self.inner, buf)
+                let big_decimal = self.to_big_decimal();
+                <BigDecimal as sqlx::Encode<sqlx::Postgres>>::encode_by_ref(&big_decimal, buf)
             }
         }
 
-        impl<'r> sqlx::Decode<'r, sqlx::Postgres> for #name {
+        impl #impl_generics_r sqlx::Decode<'r, sqlx::Postgres> for #ty #where_clause {
             fn decode(
                 value: <sqlx::Postgres as sqlx::Database>::ValueRef<'r>,
             ) -> Result<Self, sqlx::error::BoxDynError> {
-                let big_decimal = <BigDecimal as sqlx::Decode<sqlx::Postgres>>::decode(value)
-                ?;
-                Ok(#name::try_from(big_decimal)?)
+                let big_decimal = crate::decode_big_decimal(value, #int::NAME)?;
+                Ok(Self::try_from(big_decimal)?)
             }
         }
 
-        impl sqlx::postgres::PgHasArrayType for #name {
+        impl #impl_generics sqlx::postgres::PgHasArrayType for #ty #where_clause {
             fn array_type_info() -> sqlx::postgres::PgTypeInfo {
                 <Vec<BigDecimal> as sqlx::Type<sqlx::Postgres>>::type_info()
             }
         }
 
+        #[cfg(feature = "num-traits")]
+        impl #impl_generics num_traits::Zero for #ty #where_clause {
+            fn zero() -> Self {
+                Self::new(#int::ZERO)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.inner == #int::ZERO
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl #impl_generics num_traits::One for #ty #where_clause {
+            fn one() -> Self {
+                Self::new(#int::ONE)
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl #impl_generics num_traits::Num for #ty #where_clause {
+            type FromStrRadixErr = std::num::ParseIntError;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                #int::from_str_radix(str, radix).map(Self::new)
+            }
+        }
+
+        #sign_impls
+
+        #[cfg(feature = "num-traits")]
+        impl #impl_generics num_traits::Bounded for #ty #where_clause {
+            fn min_value() -> Self {
+                Self::new(#int::MIN)
+            }
+
+            fn max_value() -> Self {
+                Self::new(#int::MAX)
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl #impl_generics_to_primitive num_traits::ToPrimitive for #ty
+        #where_clause_to_primitive
+        {
+            fn to_i64(&self) -> Option<i64> {
+                num_traits::ToPrimitive::to_i64(&self.inner)
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                num_traits::ToPrimitive::to_u64(&self.inner)
+            }
+
+            fn to_i128(&self) -> Option<i128> {
+                num_traits::ToPrimitive::to_i128(&self.inner)
+            }
+
+            fn to_u128(&self) -> Option<u128> {
+                num_traits::ToPrimitive::to_u128(&self.inner)
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                num_traits::ToPrimitive::to_f64(&self.inner)
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl #impl_generics_from_primitive num_traits::FromPrimitive for #ty
+        #where_clause_from_primitive
+        {
+            fn from_i64(n: i64) -> Option<Self> {
+                <#uint as num_traits::FromPrimitive>::from_i64(n).map(Self::new)
+            }
+
+            fn from_u64(n: u64) -> Option<Self> {
+                <#uint as num_traits::FromPrimitive>::from_u64(n).map(Self::new)
+            }
+
+            fn from_i128(n: i128) -> Option<Self> {
+                <#uint as num_traits::FromPrimitive>::from_i128(n).map(Self::new)
+            }
+
+            fn from_u128(n: u128) -> Option<Self> {
+                <#uint as num_traits::FromPrimitive>::from_u128(n).map(Self::new)
+            }
+
+            fn from_f64(n: f64) -> Option<Self> {
+                <#uint as num_traits::FromPrimitive>::from_f64(n).map(Self::new)
+            }
+        }
+
+        #[cfg(feature = "num-traits")]
+        impl #impl_generics num_traits::Saturating for #ty #where_clause {
+            fn saturating_add(self, v: Self) -> Self {
+                Self::saturating_add(self, v)
+            }
+
+            fn saturating_sub(self, v: Self) -> Self {
+                Self::saturating_sub(self, v)
+            }
+        }
+
+        #(#num_traits_ops)*
+
         #[cfg(feature = "serde")]
-        impl serde::ser::Serialize for #name {
+        impl #impl_generics serde::ser::Serialize for #ty #where_clause {
             fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
             where
                 S: serde::Serializer,
             {
-                self.to_uint().serialize(serializer)
+                #int::serialize(self.inner, serializer)
             }
         }
 
         #[cfg(feature = "serde")]
-        impl<'de> serde::de::Deserialize<'de> for #name {
+        impl #impl_generics_de serde::de::Deserialize<'de> for #ty #where_clause {
             fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
             where
                 D: serde::Deserializer<'de>,
             {
                 let big_decimal = BigDecimal::deserialize(deserializer)?;
-                #name::try_from(big_decimal).map_err(serde::de::Error::custom)
+                Self::try_from(big_decimal).map_err(serde::de::Error::custom)
             }
         }
     };
 
-    gen.into()
+    Ok(gen)
+}
+
+/// Returns the type of the `inner` field, which holds the underlying integer of a `PgUint` type.
+fn inner_type(input: &DeriveInput) -> syn::Result<&Type> {
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "UIntWrapper can only be derived for structs",
+            ))
+        }
+    };
+    data.fields
+        .iter()
+        .find(|field| field.ident.as_ref().map_or(false, |ident| ident == "inner"))
+        .map(|field| &field.ty)
+        .ok_or_else(|| {
+            syn::Error::new_spanned(
+                input,
+                "UIntWrapper requires a field named `inner` holding the underlying integer",
+            )
+        })
+}
+
+/// Returns the integer types listed in the `#[uint_wrapper(...)]` attributes.
+fn attribute_types(input: &DeriveInput) -> syn::Result<Vec<Type>> {
+    let mut types = Vec::new();
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("uint_wrapper"))
+    {
+        types.extend(attr.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?);
+    }
+    Ok(types)
+}
+
+/// Returns a copy of `generics` with `lifetime` prepended to its parameters.
+fn with_lifetime(generics: &Generics, lifetime: Lifetime) -> Generics {
+    let mut generics = generics.clone();
+    generics.params.insert(0, parse_quote!(#lifetime));
+    generics
+}
+
+/// Returns a copy of `generics` with `predicate` added to its where clause.
+fn with_predicate(generics: &Generics, predicate: WherePredicate) -> Generics {
+    let mut generics = generics.clone();
+    generics.make_where_clause().predicates.push(predicate);
+    generics
 }
@@ -8,11 +8,11 @@ fn main() {
     println!("Back to u64: {}", back_to_u64);
     println!(
         "Maths work the same way as you'd expect: {}",
-        PgU64::from(67) + PgU64::from(2) * PgU64::from(3) / PgU64::from(3)
+        PgU64::from(67u64) + PgU64::from(2u64) * PgU64::from(3u64) / PgU64::from(3u64)
     );
     println!(
-        "Interact with the underlying BigDecimal type directly: {}",
-        pg_u_64.as_big_decimal()
+        "Convert to a BigDecimal when you need one: {}",
+        pg_u_64.to_big_decimal()
     );
     println!("PgUint types can be converted to and from BigDecimals, and are storable in an sqlx::Postgres database.");
     println!("If you load a PgUint from a database successfully, you can be sure that it's a valid fixed-size unsigned integer.");
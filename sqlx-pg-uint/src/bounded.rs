@@ -0,0 +1,322 @@
+use super::*;
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+/// PostgreSQL-compatible unsigned integer, restricted to the inclusive range `MIN..=MAX`
+///
+/// Wraps a [`PgUint`] and rejects values outside of the range when converting from a
+/// `BigDecimal`, parsing, deserializing or decoding. Use [`PgBounded::check_constraint`] to get the
+/// matching `CHECK` expression for the column.
+///
+/// ```
+/// use sqlx_pg_uint::{PgBounded, PgU8};
+///
+/// type Percentage = PgBounded<u8, 0, 100>;
+///
+/// assert!(Percentage::new(PgU8::from(42u8)).is_ok());
+/// assert!(Percentage::new(PgU8::from(101u8)).is_err());
+/// assert_eq!(
+///     Percentage::check_constraint("percentage"),
+///     "percentage >= 0 AND percentage <= 100"
+/// );
+/// ```
+///
+/// Ranges which are empty, or which do not fit the underlying integer type, fail to compile:
+///
+/// ```compile_fail
+/// use sqlx_pg_uint::PgBounded;
+///
+/// PgBounded::<u8, 0, 1000>::check_constraint("too_large");
+/// ```
+pub struct PgBounded<T: UIntType, const MIN: u128, const MAX: u128> {
+    inner: PgUint<T>,
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> PgBounded<T, MIN, MAX> {
+    /// Fails to compile when the range is empty, or extends beyond the range of `T`.
+    const VALID_RANGE: () = {
+        assert!(MIN <= MAX, "`MIN` must not be greater than `MAX`");
+        assert!(
+            MAX <= <T as private::Unsigned>::MAX_U128,
+            "`MAX` must not be greater than the maximum of `T`"
+        );
+    };
+
+    /// Creates a new instance of this type, returning an error if `value` is out of range.
+    pub fn new(value: PgUint<T>) -> Result<Self, Error> {
+        let () = Self::VALID_RANGE;
+        match value.to_uint().to_u128() {
+            Some(uint) if (MIN..=MAX).contains(&uint) => Ok(Self { inner: value }),
+            _ => Err(Self::out_of_range(value.to_big_decimal())),
+        }
+    }
+
+    fn out_of_range(value: BigDecimal) -> Error {
+        Error::out_of_range(
+            T::BOUNDED_NAME,
+            value,
+            BigDecimal::from(MIN),
+            BigDecimal::from(MAX),
+        )
+    }
+
+    /// Reports an error of the wrapped `PgUint` type as an error of this type, with the bounds of
+    /// this type.
+    fn map_error(error: Error) -> Error {
+        match error {
+            Error::Negative { value, .. }
+            | Error::TooLarge { value, .. }
+            | Error::TooSmall { value, .. } => Self::out_of_range(value),
+            error => error.with_type_name(T::BOUNDED_NAME),
+        }
+    }
+
+    /// Returns the wrapped `PgUint`
+    pub fn get(&self) -> PgUint<T> {
+        self.inner
+    }
+
+    /// Converts this type to the associated unsigned integer type
+    pub fn to_uint(&self) -> T {
+        self.inner.to_uint()
+    }
+
+    /// Converts this type to a `BigDecimal`
+    pub fn to_big_decimal(&self) -> BigDecimal {
+        self.inner.to_big_decimal()
+    }
+
+    /// Returns the SQL expression which constrains `column` to the range of this type, for use in
+    /// a `CHECK` constraint.
+    pub fn check_constraint(column: &str) -> String {
+        let () = Self::VALID_RANGE;
+        format!("{column} >= {MIN} AND {column} <= {MAX}")
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> std::fmt::Display for PgBounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> std::fmt::Debug for PgBounded<T, MIN, MAX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> std::str::FromStr for PgBounded<T, MIN, MAX> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.parse().map_err(Self::map_error)?)
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> TryFrom<PgUint<T>> for PgBounded<T, MIN, MAX> {
+    type Error = Error;
+
+    fn try_from(value: PgUint<T>) -> Result<Self, Self::Error> {
+        Self::new(value)
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> From<PgBounded<T, MIN, MAX>> for PgUint<T> {
+    fn from(value: PgBounded<T, MIN, MAX>) -> Self {
+        value.inner
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> TryFrom<BigDecimal> for PgBounded<T, MIN, MAX> {
+    type Error = Error;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        Self::new(PgUint::try_from(value).map_err(Self::map_error)?)
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> From<PgBounded<T, MIN, MAX>> for BigDecimal {
+    fn from(value: PgBounded<T, MIN, MAX>) -> Self {
+        value.to_big_decimal()
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> sqlx::Type<sqlx::Postgres>
+    for PgBounded<T, MIN, MAX>
+{
+    fn type_info() -> <sqlx::Postgres as sqlx::Database>::TypeInfo {
+        <BigDecimal as sqlx::Type<sqlx::Postgres>>::type_info()
+    }
+}
+
+impl<'q, T: UIntType, const MIN: u128, const MAX: u128> sqlx::Encode<'q, sqlx::Postgres>
+    for PgBounded<T, MIN, MAX>
+{
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Postgres as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <PgUint<T> as sqlx::Encode<sqlx::Postgres>>::encode_by_ref(&self.inner, buf)
+    }
+}
+
+impl<'r, T: UIntType, const MIN: u128, const MAX: u128> sqlx::Decode<'r, sqlx::Postgres>
+    for PgBounded<T, MIN, MAX>
+{
+    fn decode(
+        value: <sqlx::Postgres as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        let big_decimal = crate::decode_big_decimal(value, T::BOUNDED_NAME)?;
+        Ok(Self::try_from(big_decimal)?)
+    }
+}
+
+impl<T: UIntType, const MIN: u128, const MAX: u128> sqlx::postgres::PgHasArrayType
+    for PgBounded<T, MIN, MAX>
+{
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        <Vec<BigDecimal> as sqlx::Type<sqlx::Postgres>>::type_info()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: UIntType, const MIN: u128, const MAX: u128> serde::ser::Serialize
+    for PgBounded<T, MIN, MAX>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: UIntType, const MIN: u128, const MAX: u128> serde::de::Deserialize<'de>
+    for PgBounded<T, MIN, MAX>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let big_decimal = BigDecimal::deserialize(deserializer)?;
+        Self::try_from(big_decimal).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod pg_bounded_tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    type Percentage = PgBounded<u8, 0, 100>;
+    type Port = PgBounded<u16, 1, 65535>;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(Percentage::new(PgU8::from(0u8)).unwrap().to_uint(), 0);
+        assert_eq!(Percentage::new(PgU8::from(100u8)).unwrap().get(), 100u8);
+        assert_eq!(
+            Percentage::new(PgU8::from(101u8)).unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgBounded<PgU8>",
+                value: BigDecimal::from(101),
+                max: BigDecimal::from(100)
+            }
+        );
+        assert!(Port::new(PgU16::from(0u16)).is_err());
+        assert!(Port::new(PgU16::from(u16::MAX)).is_ok());
+    }
+
+    #[test]
+    fn try_from_bigdecimal() {
+        assert_eq!(
+            Percentage::try_from(BigDecimal::from(42))
+                .unwrap()
+                .to_uint(),
+            42
+        );
+        assert!(matches!(
+            Percentage::try_from(BigDecimal::from(200)),
+            Err(Error::TooLarge { .. })
+        ));
+        assert_eq!(
+            Percentage::try_from(BigDecimal::from(300)).unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgBounded<PgU8>",
+                value: BigDecimal::from(300),
+                max: BigDecimal::from(100)
+            }
+        );
+        assert_eq!(
+            Port::try_from(BigDecimal::from(-1)).unwrap_err(),
+            Error::TooSmall {
+                type_name: "PgBounded<PgU16>",
+                value: BigDecimal::from(-1),
+                min: BigDecimal::from(1)
+            }
+        );
+        assert_eq!(
+            Percentage::try_from(BigDecimal::new(5.into(), 1)).unwrap_err(),
+            Error::Fractional {
+                type_name: "PgBounded<PgU8>",
+                value: BigDecimal::new(5.into(), 1)
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Port::from_str("5432").unwrap().to_uint(), 5432);
+        assert_eq!(
+            Port::from_str("0").unwrap_err(),
+            Error::TooSmall {
+                type_name: "PgBounded<PgU16>",
+                value: BigDecimal::from(0),
+                min: BigDecimal::from(1)
+            }
+        );
+        assert_eq!(
+            Port::from_str("65536").unwrap_err(),
+            Error::Parse {
+                type_name: "PgBounded<PgU16>",
+                input: "65536".to_owned(),
+                kind: IntErrorKind::PosOverflow
+            }
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let percentage = Percentage::new(PgU8::from(42u8)).unwrap();
+        assert_eq!(format!("{:?}", percentage), "42");
+        assert_eq!(format!("{:?}", Some(percentage)), "Some(42)");
+        assert_eq!(format!("{:x?}", percentage), "2a");
+    }
+
+    #[test]
+    fn test_check_constraint() {
+        assert_eq!(
+            Port::check_constraint("port"),
+            "port >= 1 AND port <= 65535"
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        let percentage = Percentage::new(PgU8::from(42u8)).unwrap();
+        assert_tokens(&percentage, &[Token::U8(42)]);
+        assert_de_tokens_error::<Percentage>(
+            &[Token::U8(101)],
+            "101 is larger than 100, the maximum of PgBounded<PgU8>",
+        );
+        assert_de_tokens_error::<Percentage>(
+            &[Token::U64(300)],
+            "300 is larger than 100, the maximum of PgBounded<PgU8>",
+        );
+    }
+}
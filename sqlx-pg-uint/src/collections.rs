@@ -0,0 +1,904 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{BuildHasher, Hash};
+
+use super::*;
+
+/// Converts `PgUint` values, and containers of them, into the underlying integer types
+///
+/// Implemented for the `PgUint` types themselves and, recursively, for `Option`, `Result`, `Vec`,
+/// arrays, and `HashMap`/`BTreeMap` whose keys and values both implement it. This makes it possible
+/// to convert query results such as `Vec<PgU64>` or `Result<Option<PgU32>, sqlx::Error>` in one
+/// call. See [`PgUintKeysExt`] and [`PgUintValuesExt`] for maps where only the keys or only the
+/// values are `PgUint` types.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use sqlx_pg_uint::{IntoUints, PgU32, PgU64};
+///
+/// let ids = vec![PgU64::from(1u64), PgU64::from(2u64)];
+/// assert_eq!(ids.into_uints(), vec![1u64, 2]);
+///
+/// let row: Result<Option<PgU32>, sqlx::Error> = Ok(Some(PgU32::from(7u32)));
+/// assert_eq!(row.into_uints().unwrap(), Some(7u32));
+///
+/// let counts = HashMap::from([(PgU32::from(1u32), PgU64::from(10u64))]);
+/// assert_eq!(counts.into_uints(), HashMap::from([(1u32, 10u64)]));
+/// ```
+pub trait IntoUints {
+    /// The type with every `PgUint` replaced by its underlying integer type
+    type Uints;
+
+    /// Converts `self` into the underlying integer types
+    fn into_uints(self) -> Self::Uints;
+}
+
+/// Builds `PgUint` values, and containers of them, from the underlying integer types
+///
+/// The counterpart of [`IntoUints`], implemented for the same types.
+///
+/// ```
+/// use sqlx_pg_uint::{FromUints, PgU64};
+///
+/// let ids = Vec::<PgU64>::from_uints(vec![1, 2]);
+/// assert_eq!(ids, vec![PgU64::from(1u64), PgU64::from(2u64)]);
+/// ```
+pub trait FromUints: Sized {
+    /// The type with every `PgUint` replaced by its underlying integer type
+    type Uints;
+
+    /// Builds `Self` from the underlying integer types
+    fn from_uints(uints: Self::Uints) -> Self;
+}
+
+impl<T: UIntType> IntoUints for PgUint<T> {
+    type Uints = T;
+
+    fn into_uints(self) -> T {
+        self.to_uint()
+    }
+}
+
+impl<T: UIntType> FromUints for PgUint<T> {
+    type Uints = T;
+
+    fn from_uints(uints: T) -> Self {
+        Self::new(uints)
+    }
+}
+
+impl<T: IntoUints> IntoUints for Option<T> {
+    type Uints = Option<T::Uints>;
+
+    fn into_uints(self) -> Self::Uints {
+        self.map(T::into_uints)
+    }
+}
+
+impl<T: FromUints> FromUints for Option<T> {
+    type Uints = Option<T::Uints>;
+
+    fn from_uints(uints: Self::Uints) -> Self {
+        uints.map(T::from_uints)
+    }
+}
+
+impl<T: IntoUints, E> IntoUints for Result<T, E> {
+    type Uints = Result<T::Uints, E>;
+
+    fn into_uints(self) -> Self::Uints {
+        self.map(T::into_uints)
+    }
+}
+
+impl<T: FromUints, E> FromUints for Result<T, E> {
+    type Uints = Result<T::Uints, E>;
+
+    fn from_uints(uints: Self::Uints) -> Self {
+        uints.map(T::from_uints)
+    }
+}
+
+impl<T: IntoUints> IntoUints for Vec<T> {
+    type Uints = Vec<T::Uints>;
+
+    fn into_uints(self) -> Self::Uints {
+        self.into_iter().map(T::into_uints).collect()
+    }
+}
+
+impl<T: FromUints> FromUints for Vec<T> {
+    type Uints = Vec<T::Uints>;
+
+    fn from_uints(uints: Self::Uints) -> Self {
+        uints.into_iter().map(T::from_uints).collect()
+    }
+}
+
+impl<T: IntoUints, const N: usize> IntoUints for [T; N] {
+    type Uints = [T::Uints; N];
+
+    fn into_uints(self) -> Self::Uints {
+        self.map(T::into_uints)
+    }
+}
+
+impl<T: FromUints, const N: usize> FromUints for [T; N] {
+    type Uints = [T::Uints; N];
+
+    fn from_uints(uints: Self::Uints) -> Self {
+        uints.map(T::from_uints)
+    }
+}
+
+impl<K, V, S> IntoUints for HashMap<K, V, S>
+where
+    K: IntoUints,
+    K::Uints: Hash + Eq,
+    V: IntoUints,
+    S: BuildHasher + Default,
+{
+    type Uints = HashMap<K::Uints, V::Uints, S>;
+
+    fn into_uints(self) -> Self::Uints {
+        self.into_iter()
+            .map(|(key, value)| (key.into_uints(), value.into_uints()))
+            .collect()
+    }
+}
+
+impl<K, V, S> FromUints for HashMap<K, V, S>
+where
+    K: FromUints + Hash + Eq,
+    V: FromUints,
+    S: BuildHasher + Default,
+{
+    type Uints = HashMap<K::Uints, V::Uints, S>;
+
+    fn from_uints(uints: Self::Uints) -> Self {
+        uints
+            .into_iter()
+            .map(|(key, value)| (K::from_uints(key), V::from_uints(value)))
+            .collect()
+    }
+}
+
+impl<K, V> IntoUints for BTreeMap<K, V>
+where
+    K: IntoUints,
+    K::Uints: Ord,
+    V: IntoUints,
+{
+    type Uints = BTreeMap<K::Uints, V::Uints>;
+
+    fn into_uints(self) -> Self::Uints {
+        self.into_iter()
+            .map(|(key, value)| (key.into_uints(), value.into_uints()))
+            .collect()
+    }
+}
+
+impl<K, V> FromUints for BTreeMap<K, V>
+where
+    K: FromUints + Ord,
+    V: FromUints,
+{
+    type Uints = BTreeMap<K::Uints, V::Uints>;
+
+    fn from_uints(uints: Self::Uints) -> Self {
+        uints
+            .into_iter()
+            .map(|(key, value)| (K::from_uints(key), V::from_uints(value)))
+            .collect()
+    }
+}
+
+/// Converts `PgUint` values, and containers of them, into other integer types, failing if a value
+/// is out of range
+///
+/// The fallible counterpart of [`IntoUints`], implemented for the same containers. Like
+/// [`TryInto`], the parameter is the type to convert into, so the values can be narrowed into
+/// smaller or signed integers, or widened, which never fails. The keys and values of a map may be
+/// converted into different types.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use sqlx_pg_uint::{PgU32, PgU64, TryIntoUints};
+///
+/// let row: Result<Option<PgU64>, sqlx::Error> = Ok(Some(PgU64::from(7u64)));
+/// let narrowed: Result<Option<u8>, sqlx::Error> = row.try_into_uints().unwrap();
+/// assert_eq!(narrowed.unwrap(), Some(7u8));
+///
+/// let counts = HashMap::from([(PgU32::from(1u32), PgU64::from(300u64))]);
+/// let widened: HashMap<u64, u16> = counts.clone().try_into_uints().unwrap();
+/// assert_eq!(widened, HashMap::from([(1u64, 300u16)]));
+/// assert!(TryIntoUints::<HashMap<u32, u8>>::try_into_uints(counts).is_err());
+/// ```
+pub trait TryIntoUints<U> {
+    /// Converts `self` into `U`, failing on the first value which is out of range
+    fn try_into_uints(self) -> Result<U, Error>;
+}
+
+/// Builds `PgUint` values, and containers of them, from other integer types, failing if a value
+/// is out of range
+///
+/// The fallible counterpart of [`FromUints`], implemented for the same containers. Like
+/// [`TryFrom`], the parameter is the type to convert from, such as a wider or signed integer, or a
+/// narrower one, which never fails.
+///
+/// ```
+/// use sqlx_pg_uint::{PgU8, TryFromUints};
+///
+/// assert_eq!(
+///     <[Option<PgU8>; 2]>::try_from_uints([Some(1u64), None]).unwrap(),
+///     [Some(PgU8::from(1u8)), None]
+/// );
+/// assert!(Option::<PgU8>::try_from_uints(Some(-1i32)).is_err());
+/// ```
+pub trait TryFromUints<U>: Sized {
+    /// Builds `Self` from `uints`, failing on the first value which is out of range
+    fn try_from_uints(uints: U) -> Result<Self, Error>;
+}
+
+impl<T: UIntType, U> TryIntoUints<U> for PgUint<T>
+where
+    U: TryFrom<PgUint<T>>,
+    Error: From<<U as TryFrom<PgUint<T>>>::Error>,
+{
+    fn try_into_uints(self) -> Result<U, Error> {
+        Ok(U::try_from(self)?)
+    }
+}
+
+impl<T: UIntType, U> TryFromUints<U> for PgUint<T>
+where
+    PgUint<T>: TryFrom<U>,
+    Error: From<<PgUint<T> as TryFrom<U>>::Error>,
+{
+    fn try_from_uints(uints: U) -> Result<Self, Error> {
+        Ok(Self::try_from(uints)?)
+    }
+}
+
+impl<T: TryIntoUints<U>, U> TryIntoUints<Option<U>> for Option<T> {
+    fn try_into_uints(self) -> Result<Option<U>, Error> {
+        self.map(T::try_into_uints).transpose()
+    }
+}
+
+impl<T: TryFromUints<U>, U> TryFromUints<Option<U>> for Option<T> {
+    fn try_from_uints(uints: Option<U>) -> Result<Self, Error> {
+        uints.map(T::try_from_uints).transpose()
+    }
+}
+
+impl<T: TryIntoUints<U>, U, E> TryIntoUints<Result<U, E>> for Result<T, E> {
+    fn try_into_uints(self) -> Result<Result<U, E>, Error> {
+        match self {
+            Ok(value) => value.try_into_uints().map(Ok),
+            Err(error) => Ok(Err(error)),
+        }
+    }
+}
+
+impl<T: TryFromUints<U>, U, E> TryFromUints<Result<U, E>> for Result<T, E> {
+    fn try_from_uints(uints: Result<U, E>) -> Result<Self, Error> {
+        match uints {
+            Ok(value) => T::try_from_uints(value).map(Ok),
+            Err(error) => Ok(Err(error)),
+        }
+    }
+}
+
+impl<T: TryIntoUints<U>, U> TryIntoUints<Vec<U>> for Vec<T> {
+    fn try_into_uints(self) -> Result<Vec<U>, Error> {
+        self.into_iter().map(T::try_into_uints).collect()
+    }
+}
+
+impl<T: TryFromUints<U>, U> TryFromUints<Vec<U>> for Vec<T> {
+    fn try_from_uints(uints: Vec<U>) -> Result<Self, Error> {
+        uints.into_iter().map(T::try_from_uints).collect()
+    }
+}
+
+// `std::array::try_from_fn` is unstable, so the arrays are filled with default values first.
+impl<T: TryIntoUints<U>, U: Default, const N: usize> TryIntoUints<[U; N]> for [T; N] {
+    fn try_into_uints(self) -> Result<[U; N], Error> {
+        let mut uints: [U; N] = std::array::from_fn(|_| U::default());
+        for (uint, value) in uints.iter_mut().zip(self) {
+            *uint = value.try_into_uints()?;
+        }
+        Ok(uints)
+    }
+}
+
+impl<T: TryFromUints<U> + Default, U, const N: usize> TryFromUints<[U; N]> for [T; N] {
+    fn try_from_uints(uints: [U; N]) -> Result<Self, Error> {
+        let mut values: [T; N] = std::array::from_fn(|_| T::default());
+        for (value, uint) in values.iter_mut().zip(uints) {
+            *value = T::try_from_uints(uint)?;
+        }
+        Ok(values)
+    }
+}
+
+impl<K, V, S, KU, VU, SU> TryIntoUints<HashMap<KU, VU, SU>> for HashMap<K, V, S>
+where
+    K: TryIntoUints<KU>,
+    V: TryIntoUints<VU>,
+    KU: Hash + Eq,
+    SU: BuildHasher + Default,
+{
+    fn try_into_uints(self) -> Result<HashMap<KU, VU, SU>, Error> {
+        self.into_iter()
+            .map(|(key, value)| Ok((key.try_into_uints()?, value.try_into_uints()?)))
+            .collect()
+    }
+}
+
+impl<K, V, S, KU, VU, SU> TryFromUints<HashMap<KU, VU, SU>> for HashMap<K, V, S>
+where
+    K: TryFromUints<KU> + Hash + Eq,
+    V: TryFromUints<VU>,
+    S: BuildHasher + Default,
+{
+    fn try_from_uints(uints: HashMap<KU, VU, SU>) -> Result<Self, Error> {
+        uints
+            .into_iter()
+            .map(|(key, value)| Ok((K::try_from_uints(key)?, V::try_from_uints(value)?)))
+            .collect()
+    }
+}
+
+impl<K, V, KU, VU> TryIntoUints<BTreeMap<KU, VU>> for BTreeMap<K, V>
+where
+    K: TryIntoUints<KU>,
+    V: TryIntoUints<VU>,
+    KU: Ord,
+{
+    fn try_into_uints(self) -> Result<BTreeMap<KU, VU>, Error> {
+        self.into_iter()
+            .map(|(key, value)| Ok((key.try_into_uints()?, value.try_into_uints()?)))
+            .collect()
+    }
+}
+
+impl<K, V, KU, VU> TryFromUints<BTreeMap<KU, VU>> for BTreeMap<K, V>
+where
+    K: TryFromUints<KU> + Ord,
+    V: TryFromUints<VU>,
+{
+    fn try_from_uints(uints: BTreeMap<KU, VU>) -> Result<Self, Error> {
+        uints
+            .into_iter()
+            .map(|(key, value)| Ok((K::try_from_uints(key)?, V::try_from_uints(value)?)))
+            .collect()
+    }
+}
+
+/// Conversions for slices of `PgUint` values, which also cover `Vec`s and arrays
+///
+/// ```
+/// use sqlx_pg_uint::{PgU32, PgU64, PgUintVecExt};
+///
+/// let ids = vec![PgU64::from(1u64), PgU64::from(2u64)];
+/// assert_eq!(ids.to_uints(), vec![1u64, 2]);
+/// assert_eq!(ids.convert::<u128>(), vec![1u128, 2]);
+/// assert_eq!(ids.try_convert::<u8>().unwrap(), vec![1u8, 2]);
+/// assert_eq!(
+///     ids.try_convert::<PgU32>().unwrap(),
+///     vec![PgU32::from(1u32), PgU32::from(2u32)]
+/// );
+/// assert!([PgU64::MAX].try_convert::<u32>().is_err());
+/// ```
+pub trait PgUintVecExt<T: UIntType> {
+    /// Copies the values into a `Vec` of the underlying integer type.
+    fn to_uints(&self) -> Vec<T>;
+
+    /// Converts the values into a `Vec` of a type which holds all of them, such as a wider
+    /// primitive or `PgUint` type.
+    fn convert<U>(&self) -> Vec<U>
+    where
+        U: From<PgUint<T>>;
+
+    /// Converts the values into a `Vec` of another type, such as a narrower primitive or `PgUint`
+    /// type, failing on the first value which is out of range.
+    fn try_convert<U>(&self) -> Result<Vec<U>, Error>
+    where
+        U: TryFrom<PgUint<T>>,
+        Error: From<<U as TryFrom<PgUint<T>>>::Error>;
+}
+
+impl<T: UIntType> PgUintVecExt<T> for [PgUint<T>] {
+    fn to_uints(&self) -> Vec<T> {
+        self.iter().map(PgUint::to_uint).collect()
+    }
+
+    fn convert<U>(&self) -> Vec<U>
+    where
+        U: From<PgUint<T>>,
+    {
+        self.iter().map(|value| U::from(*value)).collect()
+    }
+
+    fn try_convert<U>(&self) -> Result<Vec<U>, Error>
+    where
+        U: TryFrom<PgUint<T>>,
+        Error: From<<U as TryFrom<PgUint<T>>>::Error>,
+    {
+        self.iter().map(|value| Ok(U::try_from(*value)?)).collect()
+    }
+}
+
+/// Conversions for slices of primitive integers into `PgUint` values, which also cover `Vec`s and
+/// arrays
+///
+/// ```
+/// use sqlx_pg_uint::{PgU32, PgU64, UintVecExt};
+///
+/// assert_eq!([1u64, 2].to_pg_uints(), vec![PgU64::from(1u64), PgU64::from(2u64)]);
+/// assert_eq!([1u32, 2].to_wider_pg_uints::<u64>(), vec![PgU64::from(1u64), PgU64::from(2u64)]);
+/// assert_eq!(
+///     vec![1u64, 2].try_to_pg_uints::<u32>().unwrap(),
+///     vec![PgU32::from(1u32), PgU32::from(2u32)]
+/// );
+/// assert!([-1i64].try_to_pg_uints::<u64>().is_err());
+/// ```
+pub trait UintVecExt<T> {
+    /// Copies the values into a `Vec` of the `PgUint` type of the same width.
+    fn to_pg_uints(&self) -> Vec<PgUint<T>>
+    where
+        T: UIntType;
+
+    /// Copies the values into a `Vec` of a wider `PgUint` type.
+    fn to_wider_pg_uints<U>(&self) -> Vec<PgUint<U>>
+    where
+        U: UIntType,
+        PgUint<U>: From<T>;
+
+    /// Converts the values into a `Vec` of a narrower `PgUint` type, or from signed integers,
+    /// failing on the first value which is out of range.
+    fn try_to_pg_uints<U>(&self) -> Result<Vec<PgUint<U>>, Error>
+    where
+        U: UIntType,
+        PgUint<U>: TryFrom<T>,
+        Error: From<<PgUint<U> as TryFrom<T>>::Error>;
+}
+
+impl<T: Copy> UintVecExt<T> for [T] {
+    fn to_pg_uints(&self) -> Vec<PgUint<T>>
+    where
+        T: UIntType,
+    {
+        self.iter().copied().map(PgUint::new).collect()
+    }
+
+    fn to_wider_pg_uints<U>(&self) -> Vec<PgUint<U>>
+    where
+        U: UIntType,
+        PgUint<U>: From<T>,
+    {
+        self.iter().copied().map(PgUint::<U>::from).collect()
+    }
+
+    fn try_to_pg_uints<U>(&self) -> Result<Vec<PgUint<U>>, Error>
+    where
+        U: UIntType,
+        PgUint<U>: TryFrom<T>,
+        Error: From<<PgUint<U> as TryFrom<T>>::Error>,
+    {
+        self.iter()
+            .map(|value| Ok(PgUint::<U>::try_from(*value)?))
+            .collect()
+    }
+}
+
+/// Converts the keys of a map into the underlying integer type, keeping the values
+///
+/// For maps where the values are not `PgUint` types. [`IntoUints`] converts keys and values at
+/// once.
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use sqlx_pg_uint::{PgU32, PgUintKeysExt};
+///
+/// let names = HashMap::from([(PgU32::from(1u32), "alice")]);
+/// assert_eq!(names.clone().into_uint_keys(), HashMap::from([(1u32, "alice")]));
+/// assert_eq!(
+///     names.try_into_uint_keys::<u8>().unwrap(),
+///     HashMap::from([(1u8, "alice")])
+/// );
+/// ```
+pub trait PgUintKeysExt {
+    /// The key type of the map
+    type Key;
+    /// The value type of the map
+    type Value;
+    /// The map with the keys converted into the underlying integer type
+    type UintKeys;
+    /// The map with the keys converted into `U`
+    type WithKeys<U>;
+
+    /// Converts the keys into the underlying integer type.
+    fn into_uint_keys(self) -> Self::UintKeys;
+
+    /// Converts the keys into `U`, such as a narrower or wider integer type, failing on the first
+    /// key which is out of range.
+    fn try_into_uint_keys<U>(self) -> Result<Self::WithKeys<U>, Error>
+    where
+        Self::Key: TryIntoUints<U>,
+        Self::WithKeys<U>: FromIterator<(U, Self::Value)>;
+}
+
+/// Converts the values of a map into the underlying integer type, keeping the keys
+///
+/// For maps where the keys are not `PgUint` types. [`IntoUints`] converts keys and values at once.
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use sqlx_pg_uint::{PgU64, PgUintValuesExt};
+///
+/// let balances = BTreeMap::from([("alice", PgU64::from(10u64))]);
+/// assert_eq!(balances.clone().into_uint_values(), BTreeMap::from([("alice", 10u64)]));
+/// assert_eq!(
+///     balances.try_into_uint_values::<i32>().unwrap(),
+///     BTreeMap::from([("alice", 10i32)])
+/// );
+/// ```
+pub trait PgUintValuesExt {
+    /// The key type of the map
+    type Key;
+    /// The value type of the map
+    type Value;
+    /// The map with the values converted into the underlying integer type
+    type UintValues;
+    /// The map with the values converted into `U`
+    type WithValues<U>;
+
+    /// Converts the values into the underlying integer type.
+    fn into_uint_values(self) -> Self::UintValues;
+
+    /// Converts the values into `U`, such as a narrower or wider integer type, failing on the
+    /// first value which is out of range.
+    fn try_into_uint_values<U>(self) -> Result<Self::WithValues<U>, Error>
+    where
+        Self::Value: TryIntoUints<U>,
+        Self::WithValues<U>: FromIterator<(Self::Key, U)>;
+}
+
+impl<K, V, S> PgUintKeysExt for HashMap<K, V, S>
+where
+    K: IntoUints,
+    K::Uints: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    type Key = K;
+    type Value = V;
+    type UintKeys = HashMap<K::Uints, V, S>;
+    type WithKeys<U> = HashMap<U, V, S>;
+
+    fn into_uint_keys(self) -> Self::UintKeys {
+        self.into_iter()
+            .map(|(key, value)| (key.into_uints(), value))
+            .collect()
+    }
+
+    fn try_into_uint_keys<U>(self) -> Result<Self::WithKeys<U>, Error>
+    where
+        Self::Key: TryIntoUints<U>,
+        Self::WithKeys<U>: FromIterator<(U, Self::Value)>,
+    {
+        self.into_iter()
+            .map(|(key, value)| Ok((key.try_into_uints()?, value)))
+            .collect()
+    }
+}
+
+impl<K, V> PgUintKeysExt for BTreeMap<K, V>
+where
+    K: IntoUints,
+    K::Uints: Ord,
+{
+    type Key = K;
+    type Value = V;
+    type UintKeys = BTreeMap<K::Uints, V>;
+    type WithKeys<U> = BTreeMap<U, V>;
+
+    fn into_uint_keys(self) -> Self::UintKeys {
+        self.into_iter()
+            .map(|(key, value)| (key.into_uints(), value))
+            .collect()
+    }
+
+    fn try_into_uint_keys<U>(self) -> Result<Self::WithKeys<U>, Error>
+    where
+        Self::Key: TryIntoUints<U>,
+        Self::WithKeys<U>: FromIterator<(U, Self::Value)>,
+    {
+        self.into_iter()
+            .map(|(key, value)| Ok((key.try_into_uints()?, value)))
+            .collect()
+    }
+}
+
+impl<K, V, S> PgUintValuesExt for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    V: IntoUints,
+    S: BuildHasher + Default,
+{
+    type Key = K;
+    type Value = V;
+    type UintValues = HashMap<K, V::Uints, S>;
+    type WithValues<U> = HashMap<K, U, S>;
+
+    fn into_uint_values(self) -> Self::UintValues {
+        self.into_iter()
+            .map(|(key, value)| (key, value.into_uints()))
+            .collect()
+    }
+
+    fn try_into_uint_values<U>(self) -> Result<Self::WithValues<U>, Error>
+    where
+        Self::Value: TryIntoUints<U>,
+        Self::WithValues<U>: FromIterator<(Self::Key, U)>,
+    {
+        self.into_iter()
+            .map(|(key, value)| Ok((key, value.try_into_uints()?)))
+            .collect()
+    }
+}
+
+impl<K, V> PgUintValuesExt for BTreeMap<K, V>
+where
+    K: Ord,
+    V: IntoUints,
+{
+    type Key = K;
+    type Value = V;
+    type UintValues = BTreeMap<K, V::Uints>;
+    type WithValues<U> = BTreeMap<K, U>;
+
+    fn into_uint_values(self) -> Self::UintValues {
+        self.into_iter()
+            .map(|(key, value)| (key, value.into_uints()))
+            .collect()
+    }
+
+    fn try_into_uint_values<U>(self) -> Result<Self::WithValues<U>, Error>
+    where
+        Self::Value: TryIntoUints<U>,
+        Self::WithValues<U>: FromIterator<(Self::Key, U)>,
+    {
+        self.into_iter()
+            .map(|(key, value)| Ok((key, value.try_into_uints()?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod collections_tests {
+    use super::*;
+
+    fn too_large_u8() -> Error {
+        Error::TooLarge {
+            type_name: "u8",
+            value: BigDecimal::from(300),
+            max: BigDecimal::from(u8::MAX),
+        }
+    }
+
+    #[test]
+    fn test_into_and_from_uints() {
+        let pg_uints = vec![PgU16::from(1u16), PgU16::from(u16::MAX)];
+        assert_eq!(pg_uints.clone().into_uints(), vec![1, u16::MAX]);
+        assert_eq!(Vec::<PgU16>::from_uints(vec![1, u16::MAX]), pg_uints);
+
+        let array = [PgU8::from(1u8), PgU8::from(2u8)];
+        assert_eq!(array.into_uints(), [1u8, 2]);
+        assert_eq!(<[PgU8; 2]>::from_uints([1, 2]), array);
+
+        let nested: Result<Option<Vec<PgU64>>, Error> = Ok(Some(vec![PgU64::from(3u64)]));
+        assert_eq!(nested.into_uints(), Ok(Some(vec![3u64])));
+        let failed: Result<Option<PgU64>, &str> = Err("connection lost");
+        assert_eq!(failed.into_uints(), Err("connection lost"));
+        assert_eq!(Result::<Option<PgU64>, ()>::from_uints(Ok(None)), Ok(None));
+    }
+
+    #[test]
+    fn test_maps() {
+        let map = HashMap::from([(PgU32::from(1u32), PgU64::from(10u64))]);
+        assert_eq!(map.clone().into_uints(), HashMap::from([(1u32, 10u64)]));
+        assert_eq!(
+            HashMap::<PgU32, PgU64>::from_uints(HashMap::from([(1, 10)])),
+            map
+        );
+
+        let map = BTreeMap::from([(PgU8::from(2u8), "b"), (PgU8::from(1u8), "a")]);
+        assert_eq!(
+            map.into_uint_keys().into_iter().collect::<Vec<_>>(),
+            vec![(1u8, "a"), (2, "b")]
+        );
+        let map = HashMap::from([("a", PgU128::from(1u128))]);
+        assert_eq!(map.into_uint_values(), HashMap::from([("a", 1u128)]));
+        let map = BTreeMap::from([(PgU8::from(1u8), PgU8::from(2u8))]);
+        assert_eq!(map.into_uints(), BTreeMap::from([(1u8, 2u8)]));
+    }
+
+    #[test]
+    fn test_slices() {
+        let pg_uints = [PgU64::from(1u64), PgU64::from(300u64)];
+        assert_eq!(pg_uints.to_uints(), vec![1u64, 300]);
+        assert_eq!(pg_uints.try_convert::<u16>(), Ok(vec![1u16, 300]));
+        assert_eq!(
+            pg_uints.try_convert::<PgU8>().unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU8",
+                value: BigDecimal::from(300),
+                max: BigDecimal::from(u8::MAX)
+            }
+        );
+        assert!(pg_uints.try_convert::<i8>().is_err());
+        assert_eq!(pg_uints.convert::<u128>(), vec![1u128, 300]);
+        assert_eq!(
+            pg_uints.convert::<PgU128>(),
+            vec![PgU128::from(1u128), PgU128::from(300u128)]
+        );
+        // Widening goes through the blanket `TryFrom` implementation, which cannot fail
+        assert_eq!(
+            [PgU32::from(u32::MAX)].try_convert::<u64>(),
+            Ok(vec![u32::MAX as u64])
+        );
+
+        assert_eq!(
+            [1u32, 2].to_pg_uints(),
+            vec![PgU32::from(1u32), PgU32::from(2u32)]
+        );
+        assert_eq!(
+            [1u128, 300].try_to_pg_uints::<u16>(),
+            Ok(vec![PgU16::from(1u16), PgU16::from(300u16)])
+        );
+        assert!([1u128, 300].try_to_pg_uints::<u8>().is_err());
+        assert_eq!(
+            [1u8, u8::MAX].to_wider_pg_uints::<u128>(),
+            vec![PgU128::from(1u128), PgU128::from(255u128)]
+        );
+        assert_eq!([1u16].try_to_pg_uints::<u64>(), Ok(vec![PgU64::from(1u64)]));
+        assert_eq!(
+            [-1i32].try_to_pg_uints::<u32>().unwrap_err(),
+            Error::Negative {
+                type_name: "PgU32",
+                value: BigDecimal::from(-1)
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_into_and_from_uints() {
+        let option = Some(PgU64::from(7u64));
+        assert_eq!(
+            TryIntoUints::<Option<u8>>::try_into_uints(option),
+            Ok(Some(7))
+        );
+        assert_eq!(
+            TryIntoUints::<Option<u128>>::try_into_uints(option),
+            Ok(Some(7))
+        );
+        assert_eq!(
+            TryIntoUints::<Option<u8>>::try_into_uints(Some(PgU64::from(300u64))),
+            Err(too_large_u8())
+        );
+        assert_eq!(
+            TryIntoUints::<Option<u8>>::try_into_uints(None::<PgU64>),
+            Ok(None)
+        );
+        assert_eq!(
+            Option::<PgU8>::try_from_uints(Some(7u64)),
+            Ok(Some(PgU8::from(7u8)))
+        );
+        assert_eq!(
+            Option::<PgU64>::try_from_uints(Some(7u8)),
+            Ok(Some(PgU64::from(7u64)))
+        );
+        assert!(Option::<PgU8>::try_from_uints(Some(300u64)).is_err());
+
+        let row: Result<Option<PgU64>, &str> = Ok(Some(PgU64::from(300u64)));
+        let widened: Result<Option<u128>, &str> = row.try_into_uints().unwrap();
+        assert_eq!(widened, Ok(Some(300)));
+        assert_eq!(
+            TryIntoUints::<Result<Option<u8>, &str>>::try_into_uints(row),
+            Err(too_large_u8())
+        );
+        let failed: Result<Option<PgU64>, &str> = Err("connection lost");
+        let narrowed: Result<Option<u8>, &str> = failed.try_into_uints().unwrap();
+        assert_eq!(narrowed, Err("connection lost"));
+        assert_eq!(
+            Result::<Option<PgU16>, &str>::try_from_uints(Ok(Some(-1i64))).unwrap_err(),
+            Error::Negative {
+                type_name: "PgU16",
+                value: BigDecimal::from(-1)
+            }
+        );
+        assert_eq!(
+            Result::<Option<PgU128>, &str>::try_from_uints(Ok(Some(1u8))),
+            Ok(Ok(Some(PgU128::from(1u128))))
+        );
+
+        let array = [PgU32::from(1u32), PgU32::from(300u32)];
+        let narrowed: [u16; 2] = array.try_into_uints().unwrap();
+        assert_eq!(narrowed, [1, 300]);
+        let widened: [i64; 2] = array.try_into_uints().unwrap();
+        assert_eq!(widened, [1, 300]);
+        assert_eq!(
+            TryIntoUints::<[u8; 2]>::try_into_uints(array),
+            Err(too_large_u8())
+        );
+        assert_eq!(<[PgU32; 2]>::try_from_uints([1u64, 300]), Ok(array));
+        assert_eq!(<[PgU32; 2]>::try_from_uints([1u8, 2]).unwrap()[1], 2u32);
+        assert!(<[PgU8; 2]>::try_from_uints([1u64, 300]).is_err());
+
+        let vec = vec![PgU16::from(1u16)];
+        let narrowed: Vec<u8> = vec.clone().try_into_uints().unwrap();
+        assert_eq!(narrowed, vec![1]);
+        assert_eq!(Vec::<PgU16>::try_from_uints(vec![1u64]), Ok(vec));
+        assert!(Vec::<PgU16>::try_from_uints(vec![u64::MAX]).is_err());
+    }
+
+    #[test]
+    fn test_try_maps() {
+        let map = HashMap::from([(PgU32::from(1u32), PgU64::from(300u64))]);
+        let converted: HashMap<u8, u16> = map.clone().try_into_uints().unwrap();
+        assert_eq!(converted, HashMap::from([(1, 300)]));
+        let widened: HashMap<u64, u128> = map.clone().try_into_uints().unwrap();
+        assert_eq!(widened, HashMap::from([(1, 300)]));
+        assert!(TryIntoUints::<HashMap<u8, u8>>::try_into_uints(map.clone()).is_err());
+        assert_eq!(
+            HashMap::<PgU32, PgU64>::try_from_uints(HashMap::from([(1i64, 300u16)])),
+            Ok(map.clone())
+        );
+        assert!(HashMap::<PgU8, PgU8>::try_from_uints(HashMap::from([(1u32, 300u32)])).is_err());
+
+        let map = BTreeMap::from([(PgU16::from(2u16), PgU8::from(3u8))]);
+        let converted: BTreeMap<u8, u64> = map.clone().try_into_uints().unwrap();
+        assert_eq!(converted, BTreeMap::from([(2, 3)]));
+        assert_eq!(
+            BTreeMap::<PgU16, PgU8>::try_from_uints(BTreeMap::from([(2u128, 3u128)])),
+            Ok(map)
+        );
+        assert!(
+            BTreeMap::<PgU16, PgU8>::try_from_uints(BTreeMap::from([(2u128, 256u128)])).is_err()
+        );
+
+        let keys = HashMap::from([(PgU64::from(300u64), "a")]);
+        assert_eq!(
+            keys.clone().try_into_uint_keys::<u16>(),
+            Ok(HashMap::from([(300u16, "a")]))
+        );
+        assert_eq!(
+            keys.clone().try_into_uint_keys::<u128>(),
+            Ok(HashMap::from([(300u128, "a")]))
+        );
+        assert_eq!(keys.try_into_uint_keys::<u8>(), Err(too_large_u8()));
+        let keys = BTreeMap::from([(PgU8::from(1u8), "a")]);
+        assert_eq!(
+            keys.try_into_uint_keys::<i8>(),
+            Ok(BTreeMap::from([(1i8, "a")]))
+        );
+
+        let values = BTreeMap::from([("a", PgU64::from(300u64))]);
+        assert_eq!(
+            values.clone().try_into_uint_values::<u32>(),
+            Ok(BTreeMap::from([("a", 300u32)]))
+        );
+        assert_eq!(values.try_into_uint_values::<u8>(), Err(too_large_u8()));
+        let values = HashMap::from([("a", Some(PgU16::from(1u16)))]);
+        assert_eq!(
+            values.try_into_uint_values::<Option<u64>>(),
+            Ok(HashMap::from([("a", Some(1u64))]))
+        );
+    }
+}
@@ -0,0 +1,230 @@
+use bigdecimal::num_bigint::BigInt;
+
+use super::*;
+use private::Integer;
+
+/// Converts a primitive integer to a `BigDecimal`, for use in errors.
+fn big_decimal(value: impl Into<BigInt>) -> BigDecimal {
+    BigDecimal::from(value.into())
+}
+
+/// Implements lossless conversions from `$narrow` to each of the wider types, and checked
+/// conversions in the other direction, between the `PgUint` types and the primitives.
+macro_rules! impl_widening {
+    ($narrow:ty => $($wide:ty),*) => {
+        $(
+            impl From<PgUint<$narrow>> for PgUint<$wide> {
+                fn from(value: PgUint<$narrow>) -> Self {
+                    Self::new(<$wide>::from(value.to_uint()))
+                }
+            }
+
+            impl From<$narrow> for PgUint<$wide> {
+                fn from(value: $narrow) -> Self {
+                    Self::new(<$wide>::from(value))
+                }
+            }
+
+            impl From<PgUint<$narrow>> for $wide {
+                fn from(value: PgUint<$narrow>) -> Self {
+                    <$wide>::from(value.to_uint())
+                }
+            }
+
+            impl TryFrom<PgUint<$wide>> for PgUint<$narrow> {
+                type Error = Error;
+
+                fn try_from(value: PgUint<$wide>) -> Result<Self, Self::Error> {
+                    <$narrow>::try_from(value.to_uint())
+                        .map(Self::new)
+                        .map_err(|_| Error::TooLarge {
+                            type_name: <$narrow as Integer>::NAME,
+                            value: value.to_big_decimal(),
+                            max: big_decimal(<$narrow>::MAX),
+                        })
+                }
+            }
+
+            impl TryFrom<$wide> for PgUint<$narrow> {
+                type Error = Error;
+
+                fn try_from(value: $wide) -> Result<Self, Self::Error> {
+                    <$narrow>::try_from(value)
+                        .map(Self::new)
+                        .map_err(|_| Error::TooLarge {
+                            type_name: <$narrow as Integer>::NAME,
+                            value: value.to_big_decimal(),
+                            max: big_decimal(<$narrow>::MAX),
+                        })
+                }
+            }
+
+            impl TryFrom<PgUint<$wide>> for $narrow {
+                type Error = Error;
+
+                fn try_from(value: PgUint<$wide>) -> Result<Self, Self::Error> {
+                    <$narrow>::try_from(value.to_uint()).map_err(|_| Error::TooLarge {
+                        type_name: stringify!($narrow),
+                        value: value.to_big_decimal(),
+                        max: big_decimal(<$narrow>::MAX),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_widening!(u8 => u16, u32, u64, u128);
+impl_widening!(u16 => u32, u64, u128);
+impl_widening!(u32 => u64, u128);
+impl_widening!(u64 => u128);
+#[cfg(feature = "u256")]
+impl_widening!(u8 => U256);
+#[cfg(feature = "u256")]
+impl_widening!(u16 => U256);
+#[cfg(feature = "u256")]
+impl_widening!(u32 => U256);
+#[cfg(feature = "u256")]
+impl_widening!(u64 => U256);
+#[cfg(feature = "u256")]
+impl_widening!(u128 => U256);
+
+/// Implements checked conversions in both directions between `PgUint<$uint>` and primitives whose
+/// range does not nest with it, such as the signed integers and `usize`.
+macro_rules! impl_checked {
+    ($uint:ty => $($prim:ty),*) => {
+        $(
+            impl TryFrom<$prim> for PgUint<$uint> {
+                type Error = Error;
+
+                fn try_from(value: $prim) -> Result<Self, Self::Error> {
+                    <$uint>::try_from(value).map(Self::new).map_err(|_| {
+                        Error::out_of_range(
+                            <$uint as Integer>::NAME,
+                            big_decimal(value),
+                            <$uint as Integer>::MIN.to_big_decimal(),
+                            <$uint as Integer>::MAX.to_big_decimal(),
+                        )
+                    })
+                }
+            }
+
+            impl TryFrom<PgUint<$uint>> for $prim {
+                type Error = Error;
+
+                fn try_from(value: PgUint<$uint>) -> Result<Self, Self::Error> {
+                    <$prim>::try_from(value.to_uint()).map_err(|_| Error::TooLarge {
+                        type_name: stringify!($prim),
+                        value: value.to_big_decimal(),
+                        max: big_decimal(<$prim>::MAX),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_checked!(u8 => i8, i16, i32, i64, i128, isize, usize);
+impl_checked!(u16 => i8, i16, i32, i64, i128, isize, usize);
+impl_checked!(u32 => i8, i16, i32, i64, i128, isize, usize);
+impl_checked!(u64 => i8, i16, i32, i64, i128, isize, usize);
+impl_checked!(u128 => i8, i16, i32, i64, i128, isize, usize);
+#[cfg(feature = "u256")]
+impl_checked!(U256 => i8, i16, i32, i64, i128, isize, usize);
+
+#[cfg(test)]
+mod conversions_tests {
+    use super::*;
+
+    #[test]
+    fn test_widening() {
+        assert_eq!(PgU16::from(PgU8::from(u8::MAX)), u8::MAX as u16);
+        assert_eq!(PgU128::from(PgU64::from(u64::MAX)), u64::MAX as u128);
+        assert_eq!(PgU64::from(42u8), 42u64);
+        assert_eq!(u128::from(PgU32::from(u32::MAX)), u32::MAX as u128);
+
+        let pg_u32: PgU32 = PgU16::from(7u16).into();
+        assert_eq!(pg_u32, 7u32);
+    }
+
+    #[test]
+    fn test_narrowing() {
+        assert_eq!(PgU8::try_from(PgU64::from(255u64)).unwrap(), 255u8);
+        assert_eq!(
+            PgU8::try_from(PgU64::from(256u64)).unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU8",
+                value: BigDecimal::from(256),
+                max: BigDecimal::from(u8::MAX)
+            }
+        );
+        assert_eq!(PgU32::try_from(u64::from(u32::MAX)).unwrap(), u32::MAX);
+        assert_eq!(
+            PgU32::try_from(u128::MAX).unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU32",
+                value: BigDecimal::from(u128::MAX),
+                max: BigDecimal::from(u32::MAX)
+            }
+        );
+        assert_eq!(u16::try_from(PgU128::from(1000u128)).unwrap(), 1000);
+        assert!(u16::try_from(PgU128::from(70000u128)).is_err());
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(PgU64::try_from(42i64).unwrap(), 42u64);
+        assert_eq!(
+            PgU64::try_from(-1i64).unwrap_err(),
+            Error::Negative {
+                type_name: "PgU64",
+                value: BigDecimal::from(-1)
+            }
+        );
+        assert_eq!(PgU8::try_from(255i32).unwrap(), 255u8);
+        assert!(PgU8::try_from(256i32).is_err());
+        assert_eq!(PgU128::try_from(i128::MAX).unwrap(), i128::MAX as u128);
+        assert!(PgU32::try_from(-5isize).is_err());
+
+        assert_eq!(
+            i64::try_from(PgU64::from(i64::MAX as u64)).unwrap(),
+            i64::MAX
+        );
+        assert_eq!(
+            i64::try_from(PgU64::from(u64::MAX)).unwrap_err(),
+            Error::TooLarge {
+                type_name: "i64",
+                value: BigDecimal::from(u64::MAX),
+                max: BigDecimal::from(i64::MAX)
+            }
+        );
+        assert_eq!(i16::try_from(PgU8::from(u8::MAX)).unwrap(), 255);
+        assert!(i8::try_from(PgU8::from(128u8)).is_err());
+    }
+
+    #[test]
+    fn test_usize() {
+        assert_eq!(PgU64::try_from(42usize).unwrap(), 42u64);
+        assert_eq!(usize::try_from(PgU16::from(u16::MAX)).unwrap(), 65535);
+        assert!(PgU8::try_from(256usize).is_err());
+        assert!(usize::try_from(PgU128::from(u128::MAX)).is_err());
+    }
+
+    #[test]
+    fn test_cross_width_cmp() {
+        assert_eq!(PgU32::from(42u32), PgU64::from(42u64));
+        assert_eq!(PgU128::from(42u128), PgU8::from(42u8));
+        assert_ne!(PgU16::from(1u16), PgU32::from(65537u32));
+        assert!(PgU8::from(u8::MAX) < PgU16::from(256u16));
+        assert!(PgU128::from(u128::MAX) > PgU64::from(u64::MAX));
+        assert!(PgU64::from(7u64) >= PgU32::from(7u32));
+        assert_eq!(
+            PgU32::from(3u32).partial_cmp(&PgU64::from(3u64)),
+            Some(std::cmp::Ordering::Equal)
+        );
+
+        let foreign_keys = [PgU32::from(1u32), PgU32::from(2u32)];
+        let primary_key = PgU64::from(2u64);
+        assert!(foreign_keys.iter().any(|key| *key == primary_key));
+    }
+}
@@ -0,0 +1,183 @@
+use super::*;
+use sqlx_pg_uint_macros::IntWrapper;
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, IntWrapper, sqlx::FromRow)]
+/// PostgreSQL-compatible signed 128-bit integer
+pub struct PgI128 {
+    inner: i128,
+}
+
+#[cfg(test)]
+mod pg_i128_tests {
+    use bigdecimal::num_bigint::BigInt;
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_to_i128() {
+        let pg_i128 = PgI128::from(-12678671i128);
+        assert_eq!(pg_i128.to_int(), -12678671i128);
+        let pg_i128 = PgI128::from(0);
+        assert_eq!(pg_i128.to_int(), 0i128);
+        let pg_i128 = PgI128::from(i128::MIN);
+        assert_eq!(pg_i128.to_int(), i128::MIN);
+        let pg_i128 = PgI128::from(i128::MAX);
+        assert_eq!(pg_i128.to_int(), i128::MAX);
+    }
+
+    #[test]
+    fn test_add() {
+        let pg_i128 = PgI128::from(12678671i128);
+        let pg_i1282 = PgI128::from(-12678672i128);
+        assert_eq!((pg_i128 + pg_i1282).to_int(), -1i128);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow() {
+        let pg_i128 = PgI128::from(i128::MAX);
+        let pg_i1282 = PgI128::from(1i128);
+        let _ = pg_i128 + pg_i1282;
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_underflow() {
+        let pg_i128 = PgI128::from(i128::MIN);
+        let pg_i1282 = PgI128::from(1i128);
+        let _ = pg_i128 - pg_i1282;
+    }
+
+    #[test]
+    fn test_neg() {
+        let pg_i128 = PgI128::from(5i128);
+        assert_eq!(-pg_i128, PgI128::from(-5i128));
+        assert_eq!(-&pg_i128, -5i128);
+        assert_eq!(
+            PgI128::from(i128::MIN).checked_mul(PgI128::from(-1i128)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_checked_ops() {
+        let max = PgI128::from(i128::MAX);
+        let min = PgI128::from(i128::MIN);
+        let one = PgI128::from(1i128);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(min.checked_sub(one), None);
+        assert_eq!(min.checked_div(PgI128::from(-1i128)), None);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(min.saturating_sub(one), min);
+        assert_eq!(max.wrapping_add(one), min);
+        assert_eq!(min.overflowing_sub(one), (max, true));
+    }
+
+    #[test]
+    fn try_from_bigdecimal() {
+        let pg_i128 = PgI128::try_from(BigDecimal::from(-12678671i128)).unwrap();
+        assert_eq!(pg_i128.to_int(), -12678671i128);
+
+        let pg_i128 = PgI128::try_from(BigDecimal::from(i128::MIN)).unwrap();
+        assert_eq!(pg_i128.to_int(), i128::MIN);
+
+        let pg_i128 = PgI128::try_from(BigDecimal::from(i128::MAX)).unwrap();
+        assert_eq!(pg_i128.to_int(), i128::MAX);
+
+        let pg_i128 = PgI128::try_from(BigDecimal::from_str("-5.00").unwrap()).unwrap();
+        assert_eq!(pg_i128.to_int(), -5i128);
+
+        let fractional = BigDecimal::from(-3) / BigDecimal::from(2);
+        let pg_i128 = PgI128::try_from(fractional.clone());
+        assert_eq!(
+            pg_i128.unwrap_err(),
+            Error::Fractional {
+                type_name: "PgI128",
+                value: fractional
+            }
+        );
+
+        let too_large = BigDecimal::from(i128::MAX) + BigDecimal::from(1);
+        let pg_i128 = PgI128::try_from(too_large.clone());
+        assert_eq!(
+            pg_i128.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgI128",
+                value: too_large,
+                max: BigDecimal::from(i128::MAX)
+            }
+        );
+
+        let too_small = BigDecimal::from(BigInt::from(i128::MIN) - 1);
+        let pg_i128 = PgI128::try_from(too_small.clone());
+        assert_eq!(
+            pg_i128.unwrap_err(),
+            Error::TooSmall {
+                type_name: "PgI128",
+                value: too_small,
+                min: BigDecimal::from(i128::MIN)
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(PgI128::from_str("-42").unwrap(), -42i128);
+        assert_eq!(PgI128::from_str(&i128::MIN.to_string()).unwrap(), i128::MIN);
+        assert!(PgI128::from_str("170141183460469231731687303715884105728").is_err());
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{Bounded, Signed};
+
+        assert_eq!(PgI128::min_value(), PgI128::from(i128::MIN));
+        assert_eq!(PgI128::from(-5i128).abs(), PgI128::from(5i128));
+        assert_eq!(PgI128::from(-5i128).signum(), PgI128::from(-1i128));
+        assert!(PgI128::from(-5i128).is_negative());
+        assert_eq!(
+            PgI128::from(3i128).abs_sub(&PgI128::from(5i128)),
+            PgI128::from(0i128)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens, Token};
+
+        assert_de_tokens(&PgI128::from(-42i128), &[Token::I64(-42)]);
+        assert_de_tokens(
+            &PgI128::from(i128::MIN),
+            &[Token::Str("-170141183460469231731687303715884105728")],
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        assert_eq!(PgI128::parse_lenient("-0x10").unwrap(), -16i128);
+        assert_eq!(PgI128::parse_lenient("-1_000").unwrap(), -1000i128);
+        assert_eq!(PgI128::parse_lenient("-2e3").unwrap(), -2000i128);
+        assert_eq!(PgI128::from_str_radix("-ff", 16).unwrap(), -255i128);
+    }
+
+    #[test]
+    fn test_fmt() {
+        let pg_i128 = PgI128::from(-42i128);
+        assert_eq!(format!("{:>5}", pg_i128), "  -42");
+        assert_eq!(format!("{:+}", PgI128::from(42i128)), "+42");
+        assert_eq!(format!("{:x}", pg_i128), format!("{:x}", -42i128));
+        assert_eq!(format!("{:e}", pg_i128), "-4.2e1");
+        assert_eq!(format!("{:?}", pg_i128), "-42");
+    }
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(PgI128::MIN, i128::MIN);
+        assert_eq!(PgI128::MAX.get(), i128::MAX);
+        assert_eq!(PgI128::BITS, 128);
+        assert_eq!(PgI128::NUMERIC_PRECISION, 39);
+    }
+}
@@ -5,6 +5,18 @@
     missing_copy_implementations
 )]
 #![deny(unsafe_code)]
+// Values are validated when they are constructed, so nothing outside of the tests should need to
+// panic. Arithmetic operators behave like those of the primitives: they panic on division by zero,
+// and on overflow in debug builds, while overflow wraps around in release builds.
+#![cfg_attr(
+    not(test),
+    deny(
+        clippy::unwrap_used,
+        clippy::expect_used,
+        clippy::panic,
+        clippy::indexing_slicing
+    )
+)]
 
 /*!
 # sqlx-pg-uint
@@ -26,73 +38,604 @@ let back_to_u64: u64 = pg_u_64.to_uint();
 println!("Back to u64: {}", back_to_u64);
 println!(
     "Maths work the same way as you'd expect: {}",
-    PgU64::from(67) + PgU64::from(2) * PgU64::from(3) / PgU64::from(3)
+    PgU64::from(67u64) + PgU64::from(2u64) * PgU64::from(3u64) / PgU64::from(3u64)
 );
 println!(
-    "Interact with the underlying BigDecimal type directly: {}",
-    pg_u_64.as_big_decimal()
+    "Convert to a BigDecimal when you need one: {}",
+    pg_u_64.to_big_decimal()
 );
 println!("PgUint types can be converted to and from BigDecimals, and are storable in an sqlx::Postgres database.");
 println!("If you load a PgUint from a database successfully, you can be sure that it's a valid fixed-size unsigned integer.");
 ```
 */
 
+mod bounded;
+mod collections;
+mod conversions;
+mod i128;
+mod non_zero;
+mod parse;
+mod pg_uint;
 mod u128;
 mod u16;
+#[cfg(feature = "u256")]
+mod u256;
 mod u32;
 mod u64;
 mod u8;
 
-use std::fmt::Display;
-use std::str::FromStr;
-
 pub(crate) use bigdecimal::BigDecimal;
+use bigdecimal::Zero;
+pub use bounded::*;
+pub use collections::*;
+pub use i128::*;
+pub use non_zero::*;
+pub use pg_uint::*;
+use std::num::{IntErrorKind, ParseIntError};
+
 use thiserror::Error;
 pub use u128::*;
 pub use u16::*;
+#[cfg(feature = "u256")]
+pub use u256::*;
 pub use u32::*;
 pub use u64::*;
 pub use u8::*;
 
 #[derive(Debug, PartialEq, Clone, Error)]
-/// Error type for conversions between `BigDecimal` and `PgUint` types.
+/// Error type for conversions to `PgUint` types.
+///
+/// Every variant carries the name of the type the conversion targeted, e.g. `"PgU64"`.
 pub enum Error {
-    #[error("Value is either too large, to small or not an integer")]
-    /// Error when the value is either too large, too small or not an integer.
-    InvalidValue(BigDecimal),
-    #[error("Invalid value for target type")]
+    #[error("{value} is negative, which is not allowed for {type_name}")]
+    /// Provided value is negative, which is not supported by the unsigned target type.
+    Negative {
+        /// The name of the target type
+        type_name: &'static str,
+        /// The rejected value
+        value: BigDecimal,
+    },
+    #[error("{value} is larger than {max}, the maximum of {type_name}")]
+    /// Provided value is larger than the maximum of the target type.
+    TooLarge {
+        /// The name of the target type
+        type_name: &'static str,
+        /// The rejected value
+        value: BigDecimal,
+        /// The maximum of the target type
+        max: BigDecimal,
+    },
+    #[error("{value} is smaller than {min}, the minimum of {type_name}")]
+    /// Provided value is smaller than the minimum of a signed or bounded target type.
+    TooSmall {
+        /// The name of the target type
+        type_name: &'static str,
+        /// The rejected value
+        value: BigDecimal,
+        /// The minimum of the target type
+        min: BigDecimal,
+    },
+    #[error("{value} is not an integer, which is required for {type_name}")]
     /// Provided value is a floating point number, which is not supported by the target type.
-    Fractional(BigDecimal),
+    Fractional {
+        /// The name of the target type
+        type_name: &'static str,
+        /// The rejected value
+        value: BigDecimal,
+    },
+    #[error("{value} is not a number, which is required for {type_name}")]
+    /// PostgreSQL returned one of the special `NUMERIC` values `NaN`, `Infinity` or `-Infinity`.
+    NotANumber {
+        /// The name of the target type
+        type_name: &'static str,
+        /// The special value, as PostgreSQL spells it
+        value: String,
+    },
+    #[error("{value} is zero, which is not allowed for {type_name}")]
+    /// Provided value is zero, which is not supported by the `PgNonZero` types.
+    Zero {
+        /// The name of the target type
+        type_name: &'static str,
+        /// The rejected value
+        value: BigDecimal,
+    },
+    #[error("Cannot parse {input:?} as {type_name}: {kind:?}")]
+    /// Provided string is not a valid integer of the target type.
+    Parse {
+        /// The name of the target type
+        type_name: &'static str,
+        /// The string which failed to parse
+        input: String,
+        /// The reason parsing failed
+        kind: IntErrorKind,
+    },
     #[cfg(feature = "serde")]
     #[error(transparent)]
     /// Error when deserializing a `BigDecimal` from a `serde` deserializer.
     Serde(#[from] serde::de::value::Error),
-    #[error(transparent)]
-    /// Error when parsing a `BigDecimal` from a string.
-    ParseInt(#[from] std::num::ParseIntError),
 }
 
-mod private {
-    pub trait Sealed {}
+impl Error {
+    /// Returns the error for a `value` which lies outside of `min..=max`, the range of
+    /// `type_name`.
+    pub(crate) fn out_of_range(
+        type_name: &'static str,
+        value: BigDecimal,
+        min: BigDecimal,
+        max: BigDecimal,
+    ) -> Self {
+        if value > max {
+            Error::TooLarge {
+                type_name,
+                value,
+                max,
+            }
+        } else if min.is_zero() && value < min {
+            Error::Negative { type_name, value }
+        } else {
+            Error::TooSmall {
+                type_name,
+                value,
+                min,
+            }
+        }
+    }
+
+    /// Returns this error with `type_name` as the name of the target type, for types which wrap
+    /// another type and report its errors as their own.
+    pub(crate) fn with_type_name(self, type_name: &'static str) -> Self {
+        match self {
+            Error::Negative { value, .. } => Error::Negative { type_name, value },
+            Error::TooLarge { value, max, .. } => Error::TooLarge {
+                type_name,
+                value,
+                max,
+            },
+            Error::TooSmall { value, min, .. } => Error::TooSmall {
+                type_name,
+                value,
+                min,
+            },
+            Error::Fractional { value, .. } => Error::Fractional { type_name, value },
+            Error::NotANumber { value, .. } => Error::NotANumber { type_name, value },
+            Error::Zero { value, .. } => Error::Zero { type_name, value },
+            Error::Parse { input, kind, .. } => Error::Parse {
+                type_name,
+                input,
+                kind,
+            },
+            #[cfg(feature = "serde")]
+            Error::Serde(error) => Error::Serde(error),
+        }
+    }
+
+    /// Returns the error for a string `input` which failed to parse as `type_name`.
+    // `IntErrorKind` is only `Copy` since Rust 1.84, which is newer than the MSRV.
+    #[allow(clippy::clone_on_copy)]
+    pub(crate) fn parse(type_name: &'static str, input: &str, error: ParseIntError) -> Self {
+        Error::Parse {
+            type_name,
+            input: input.to_owned(),
+            kind: error.kind().clone(),
+        }
+    }
 }
 
-impl private::Sealed for u8 {}
-impl private::Sealed for u16 {}
-impl private::Sealed for u32 {}
-impl private::Sealed for u64 {}
-impl private::Sealed for u128 {}
+/// Lets generic code which accepts any `TryFrom` conversion whose error converts into [`enum@Error`]
+/// take lossless conversions as well, which fail with `Infallible`.
+impl From<std::convert::Infallible> for Error {
+    fn from(never: std::convert::Infallible) -> Self {
+        match never {}
+    }
+}
 
-/// Helper trait to define the underlying integer type for a given `PgUint` type. Used in the
-/// `sqlx-pg-uint-macros` crate to generate the necessary code for the `UIntWrapper` derive.
+/// Rejects `value` if it has more than `precision` integer digits, or if it is a non-zero number
+/// smaller than one, before anything is done with it that depends on its exponent. Rescaling or
+/// checking `1e100000000` for a fractional part materializes a number with as many digits as the
+/// exponent, which takes seconds of CPU time for a few bytes of input.
 ///
-/// Not intended to be implemented by users, nor is it required to be used directly.
-pub trait UIntType: private::Sealed + Display {
-    /// The underlying integer type for the `PgUint` type.
-    type Uint: private::Sealed + FromStr;
+/// Zero is returned normalized, as it may carry any scale.
+pub(crate) fn check_magnitude(
+    type_name: &'static str,
+    value: BigDecimal,
+    precision: u32,
+    min: BigDecimal,
+    max: BigDecimal,
+) -> Result<BigDecimal, Error> {
+    let (digits, scale) = value.as_bigint_and_scale();
+    if digits.is_zero() {
+        return Ok(BigDecimal::zero());
+    }
+    let integer_digits = i64::try_from(value.digits())
+        .unwrap_or(i64::MAX)
+        .saturating_sub(scale);
+    if integer_digits > i64::from(precision) {
+        Err(Error::out_of_range(type_name, value, min, max))
+    } else if integer_digits <= 0 {
+        Err(Error::Fractional { type_name, value })
+    } else {
+        Ok(value)
+    }
+}
+
+/// Decodes a `NUMERIC` value as a `BigDecimal`, rejecting the special values `NaN`, `Infinity`
+/// and `-Infinity` with [`Error::NotANumber`].
+pub(crate) fn decode_big_decimal(
+    value: sqlx::postgres::PgValueRef<'_>,
+    type_name: &'static str,
+) -> Result<BigDecimal, sqlx::error::BoxDynError> {
+    if let Some(special) = special_numeric(value.format(), value.as_bytes()?) {
+        return Err(Error::NotANumber {
+            type_name,
+            value: special.to_owned(),
+        }
+        .into());
+    }
+    <BigDecimal as sqlx::Decode<sqlx::Postgres>>::decode(value)
+}
+
+/// Returns the special `NUMERIC` value encoded in `bytes`, if any.
+fn special_numeric(format: sqlx::postgres::PgValueFormat, bytes: &[u8]) -> Option<&'static str> {
+    match format {
+        // The binary format starts with the number of digits, the weight and the sign, each of
+        // which is two bytes long. The sign doubles as the marker for the special values.
+        sqlx::postgres::PgValueFormat::Binary => match bytes.get(4..6) {
+            Some([0xC0, 0x00]) => Some("NaN"),
+            Some([0xD0, 0x00]) => Some("Infinity"),
+            Some([0xF0, 0x00]) => Some("-Infinity"),
+            _ => None,
+        },
+        sqlx::postgres::PgValueFormat::Text => match bytes {
+            b"NaN" => Some("NaN"),
+            b"Infinity" => Some("Infinity"),
+            b"-Infinity" => Some("-Infinity"),
+            _ => None,
+        },
+    }
+}
+
+mod private {
+    use std::fmt::{Binary, Debug, Display, Formatter, LowerHex, Octal, UpperHex};
+    use std::hash::Hash;
+    use std::num::ParseIntError;
+    use std::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub};
+
+    use bigdecimal::num_bigint::BigInt;
+
+    use crate::BigDecimal;
+
+    pub trait Sealed {}
+
+    /// Operations on the integer types which the generic `PgUint` implementations rely on. Most of
+    /// them are inherent methods on the primitives, which generic code cannot call. Conversions
+    /// and formatting which `U256` cannot get through the standard traits are methods here as well.
+    pub trait Integer:
+        Sealed
+        + Copy
+        + Ord
+        + Hash
+        + Debug
+        + Display
+        + LowerHex
+        + UpperHex
+        + Octal
+        + Binary
+        + Default
+        + Add<Output = Self>
+        + Sub<Output = Self>
+        + Mul<Output = Self>
+        + Div<Output = Self>
+        + Rem<Output = Self>
+        + BitAnd<Output = Self>
+        + BitOr<Output = Self>
+        + BitXor<Output = Self>
+        + Not<Output = Self>
+        + Shl<u32, Output = Self>
+        + Shr<u32, Output = Self>
+        + Send
+        + Sync
+        + 'static
+    {
+        /// The name of the `PgUint` type wrapping the integer type, used in errors.
+        const NAME: &'static str;
+
+        /// The precision of the `NUMERIC` column type which fits every value of the integer type.
+        const NUMERIC_PRECISION: u32;
+
+        const ZERO: Self;
+        const ONE: Self;
+        const MIN: Self;
+        const MAX: Self;
+        const BITS: u32;
+
+        fn checked_add(self, rhs: Self) -> Option<Self>;
+        fn checked_sub(self, rhs: Self) -> Option<Self>;
+        fn checked_mul(self, rhs: Self) -> Option<Self>;
+        fn checked_div(self, rhs: Self) -> Option<Self>;
+        fn checked_rem(self, rhs: Self) -> Option<Self>;
+        fn wrapping_add(self, rhs: Self) -> Self;
+        fn wrapping_sub(self, rhs: Self) -> Self;
+        fn wrapping_mul(self, rhs: Self) -> Self;
+        fn wrapping_div(self, rhs: Self) -> Self;
+        fn wrapping_rem(self, rhs: Self) -> Self;
+        fn saturating_add(self, rhs: Self) -> Self;
+        fn saturating_sub(self, rhs: Self) -> Self;
+        fn saturating_mul(self, rhs: Self) -> Self;
+        fn saturating_div(self, rhs: Self) -> Self;
+        fn overflowing_add(self, rhs: Self) -> (Self, bool);
+        fn overflowing_sub(self, rhs: Self) -> (Self, bool);
+        fn overflowing_mul(self, rhs: Self) -> (Self, bool);
+        fn overflowing_div(self, rhs: Self) -> (Self, bool);
+        fn overflowing_rem(self, rhs: Self) -> (Self, bool);
+        /// Like the inherent `from_str_radix`, but fails with `IntErrorKind::InvalidDigit` instead
+        /// of panicking if `radix` is not in `2..=36`.
+        fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+        /// Converts a `BigInt`, returning `None` if it is out of range.
+        fn try_from_big_int(value: &BigInt) -> Option<Self>;
+        fn to_big_decimal(self) -> BigDecimal;
+        fn fmt_lower_exp(&self, f: &mut Formatter<'_>) -> std::fmt::Result;
+        fn fmt_upper_exp(&self, f: &mut Formatter<'_>) -> std::fmt::Result;
+        #[cfg(feature = "serde")]
+        fn serialize<S: serde::Serializer>(self, serializer: S) -> Result<S::Ok, S::Error>;
+    }
+
+    macro_rules! impl_integer {
+        ($($int:ty => $name:literal, $precision:literal);*) => {
+            $(
+                impl Sealed for $int {}
+
+                impl Integer for $int {
+                    const NAME: &'static str = $name;
+                    const NUMERIC_PRECISION: u32 = $precision;
+
+                    const ZERO: Self = 0;
+                    const ONE: Self = 1;
+                    const MIN: Self = <$int>::MIN;
+                    const MAX: Self = <$int>::MAX;
+                    const BITS: u32 = <$int>::BITS;
+
+                    fn checked_add(self, rhs: Self) -> Option<Self> { self.checked_add(rhs) }
+                    fn checked_sub(self, rhs: Self) -> Option<Self> { self.checked_sub(rhs) }
+                    fn checked_mul(self, rhs: Self) -> Option<Self> { self.checked_mul(rhs) }
+                    fn checked_div(self, rhs: Self) -> Option<Self> { self.checked_div(rhs) }
+                    fn checked_rem(self, rhs: Self) -> Option<Self> { self.checked_rem(rhs) }
+                    fn wrapping_add(self, rhs: Self) -> Self { self.wrapping_add(rhs) }
+                    fn wrapping_sub(self, rhs: Self) -> Self { self.wrapping_sub(rhs) }
+                    fn wrapping_mul(self, rhs: Self) -> Self { self.wrapping_mul(rhs) }
+                    fn wrapping_div(self, rhs: Self) -> Self { self.wrapping_div(rhs) }
+                    fn wrapping_rem(self, rhs: Self) -> Self { self.wrapping_rem(rhs) }
+                    fn saturating_add(self, rhs: Self) -> Self { self.saturating_add(rhs) }
+                    fn saturating_sub(self, rhs: Self) -> Self { self.saturating_sub(rhs) }
+                    fn saturating_mul(self, rhs: Self) -> Self { self.saturating_mul(rhs) }
+                    fn saturating_div(self, rhs: Self) -> Self { self.saturating_div(rhs) }
+                    fn overflowing_add(self, rhs: Self) -> (Self, bool) { self.overflowing_add(rhs) }
+                    fn overflowing_sub(self, rhs: Self) -> (Self, bool) { self.overflowing_sub(rhs) }
+                    fn overflowing_mul(self, rhs: Self) -> (Self, bool) { self.overflowing_mul(rhs) }
+                    fn overflowing_div(self, rhs: Self) -> (Self, bool) { self.overflowing_div(rhs) }
+                    fn overflowing_rem(self, rhs: Self) -> (Self, bool) { self.overflowing_rem(rhs) }
+
+                    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                        if !(2..=36).contains(&radix) {
+                            // The primitives panic on such a radix, and `ParseIntError` cannot be
+                            // constructed directly, so the error comes from parsing an invalid
+                            // digit instead.
+                            return "?".parse::<$int>();
+                        }
+                        <$int>::from_str_radix(src, radix)
+                    }
+
+                    fn try_from_big_int(value: &BigInt) -> Option<Self> {
+                        <$int>::try_from(value).ok()
+                    }
+
+                    fn to_big_decimal(self) -> BigDecimal {
+                        BigDecimal::from(self)
+                    }
+
+                    fn fmt_lower_exp(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                        std::fmt::LowerExp::fmt(self, f)
+                    }
+
+                    fn fmt_upper_exp(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                        std::fmt::UpperExp::fmt(self, f)
+                    }
+
+                    #[cfg(feature = "serde")]
+                    fn serialize<S: serde::Serializer>(
+                        self,
+                        serializer: S,
+                    ) -> Result<S::Ok, S::Error> {
+                        serde::Serialize::serialize(&self, serializer)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_integer!(
+        u8 => "PgU8", 3;
+        u16 => "PgU16", 5;
+        u32 => "PgU32", 10;
+        u64 => "PgU64", 20;
+        u128 => "PgU128", 39;
+        i128 => "PgI128", 39
+    );
+
+    /// Operations which only the unsigned primitive integer types have.
+    pub trait Unsigned: Integer {
+        /// The `std::num::NonZero*` counterpart of the integer type.
+        type NonZero: Copy + Ord + Hash + Debug + Display + Send + Sync + 'static;
+
+        /// The name of the `PgNonZero` type wrapping the integer type, used in errors.
+        const NON_ZERO_NAME: &'static str;
+        /// The name of the `PgBounded` types wrapping the integer type, used in errors.
+        const BOUNDED_NAME: &'static str;
+        /// `MAX` as a `u128`, for use in constant expressions. Saturates at `u128::MAX` for wider
+        /// integer types.
+        const MAX_U128: u128;
+
+        fn pow(self, exp: u32) -> Self;
+        fn checked_pow(self, exp: u32) -> Option<Self>;
+        fn isqrt(self) -> Self;
+        fn ilog2(self) -> u32;
+        fn ilog10(self) -> u32;
+        fn count_ones(self) -> u32;
+        fn leading_zeros(self) -> u32;
+        fn trailing_zeros(self) -> u32;
+        fn rotate_left(self, n: u32) -> Self;
+        fn rotate_right(self, n: u32) -> Self;
+        fn swap_bytes(self) -> Self;
+        fn is_power_of_two(self) -> bool;
+        fn next_power_of_two(self) -> Self;
+        fn div_ceil(self, rhs: Self) -> Self;
+        fn abs_diff(self, other: Self) -> Self;
+        fn midpoint(self, rhs: Self) -> Self;
+        fn to_non_zero(self) -> Option<Self::NonZero>;
+        fn from_non_zero(value: Self::NonZero) -> Self;
+        /// Converts to a `u128`, returning `None` if the value does not fit.
+        fn to_u128(self) -> Option<u128>;
+    }
+
+    macro_rules! impl_unsigned {
+        ($($uint:ty => $non_zero:ty, $non_zero_name:literal, $bounded_name:literal);*) => {
+            $(
+                impl Unsigned for $uint {
+                    type NonZero = $non_zero;
+
+                    const NON_ZERO_NAME: &'static str = $non_zero_name;
+                    const BOUNDED_NAME: &'static str = $bounded_name;
+                    const MAX_U128: u128 = <$uint>::MAX as u128;
+
+                    fn pow(self, exp: u32) -> Self { self.pow(exp) }
+                    fn checked_pow(self, exp: u32) -> Option<Self> { self.checked_pow(exp) }
+                    fn ilog2(self) -> u32 { self.ilog2() }
+                    fn ilog10(self) -> u32 { self.ilog10() }
+                    fn count_ones(self) -> u32 { self.count_ones() }
+                    fn leading_zeros(self) -> u32 { self.leading_zeros() }
+                    fn trailing_zeros(self) -> u32 { self.trailing_zeros() }
+                    fn rotate_left(self, n: u32) -> Self { self.rotate_left(n) }
+                    fn rotate_right(self, n: u32) -> Self { self.rotate_right(n) }
+                    fn swap_bytes(self) -> Self { self.swap_bytes() }
+                    fn is_power_of_two(self) -> bool { self.is_power_of_two() }
+                    fn next_power_of_two(self) -> Self { self.next_power_of_two() }
+                    fn div_ceil(self, rhs: Self) -> Self { self.div_ceil(rhs) }
+                    fn abs_diff(self, other: Self) -> Self { self.abs_diff(other) }
+
+                    // `isqrt` and `midpoint` are only stable since Rust 1.84 and 1.85, which is
+                    // newer than the MSRV.
+                    fn isqrt(self) -> Self {
+                        if self < 2 {
+                            return self;
+                        }
+                        // Newton's method, starting from a power of two which is at least the
+                        // square root, converges from above.
+                        let bits = <$uint>::BITS - self.leading_zeros();
+                        let mut root: Self = 1 << bits.div_ceil(2);
+                        loop {
+                            let next = (root + self / root) / 2;
+                            if next >= root {
+                                return root;
+                            }
+                            root = next;
+                        }
+                    }
+
+                    fn midpoint(self, rhs: Self) -> Self {
+                        (self & rhs) + ((self ^ rhs) >> 1)
+                    }
+
+                    fn to_non_zero(self) -> Option<Self::NonZero> {
+                        <$non_zero>::new(self)
+                    }
+
+                    fn from_non_zero(value: Self::NonZero) -> Self {
+                        value.get()
+                    }
+
+                    fn to_u128(self) -> Option<u128> {
+                        Some(self as u128)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_unsigned!(
+        u8 => std::num::NonZeroU8, "PgNonZeroU8", "PgBounded<PgU8>";
+        u16 => std::num::NonZeroU16, "PgNonZeroU16", "PgBounded<PgU16>";
+        u32 => std::num::NonZeroU32, "PgNonZeroU32", "PgBounded<PgU32>";
+        u64 => std::num::NonZeroU64, "PgNonZeroU64", "PgBounded<PgU64>";
+        u128 => std::num::NonZeroU128, "PgNonZeroU128", "PgBounded<PgU128>"
+    );
 }
 
+/// Marker trait for the unsigned integer types which can be stored in a [`PgUint`].
+///
+/// Implemented for `u8`, `u16`, `u32`, `u64` and `u128`, and for `U256` with the `u256` feature.
+/// Not intended to be implemented by users, but can be used as a bound in generic code over
+/// [`PgUint`].
+pub trait UIntType: private::Unsigned {}
+
+impl UIntType for u8 {}
+impl UIntType for u16 {}
+impl UIntType for u32 {}
+impl UIntType for u64 {}
+impl UIntType for u128 {}
+
 /// Allows for converting an `Option<PgUInt>` to an `Option<[underlying integer type]>`
-pub trait OptionPgUint<T: UIntType> {
+pub trait OptionPgUint<T> {
     /// Convert any `Option<PgUint>` to an `Option<[underlying integer type]>`
-    fn to_option_uint(&self) -> Option<T::Uint>;
+    fn to_option_uint(&self) -> Option<T>;
+}
+
+#[cfg(test)]
+mod error_tests {
+    use sqlx::postgres::PgValueFormat;
+
+    use super::*;
+
+    #[test]
+    fn test_messages() {
+        let err = PgU8::try_from(BigDecimal::from(256)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "256 is larger than 255, the maximum of PgU8"
+        );
+        let err = PgU32::try_from(BigDecimal::from(-3)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "-3 is negative, which is not allowed for PgU32"
+        );
+        let err = "12a".parse::<PgU64>().unwrap_err();
+        assert_eq!(
+            err,
+            Error::Parse {
+                type_name: "PgU64",
+                input: "12a".to_owned(),
+                kind: IntErrorKind::InvalidDigit
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "Cannot parse \"12a\" as PgU64: InvalidDigit"
+        );
+    }
+
+    #[test]
+    fn test_special_numeric() {
+        let nan = [0, 0, 0, 0, 0xC0, 0, 0, 0];
+        assert_eq!(special_numeric(PgValueFormat::Binary, &nan), Some("NaN"));
+        let infinity = [0, 0, 0, 0, 0xD0, 0, 0, 0];
+        assert_eq!(
+            special_numeric(PgValueFormat::Binary, &infinity),
+            Some("Infinity")
+        );
+        let one = [0, 1, 0, 0, 0, 0, 0, 0, 0, 1];
+        assert_eq!(special_numeric(PgValueFormat::Binary, &one), None);
+        assert_eq!(
+            special_numeric(PgValueFormat::Text, b"-Infinity"),
+            Some("-Infinity")
+        );
+        assert_eq!(special_numeric(PgValueFormat::Text, b"42"), None);
+    }
 }
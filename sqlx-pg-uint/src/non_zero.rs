@@ -0,0 +1,327 @@
+use std::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8};
+
+use super::*;
+use private::Unsigned;
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+/// PostgreSQL-compatible unsigned integer which is known not to be zero
+///
+/// The non-zero counterpart of [`PgUint`], backed by the matching `std::num::NonZero*` type. Zero is
+/// rejected when converting, parsing, deserializing or decoding, which makes these types a good fit
+/// for ID columns. Like the standard library types, `Option<PgNonZeroU64>` is the same size as
+/// `PgNonZeroU64`.
+pub struct PgNonZeroUint<T: UIntType> {
+    inner: <T as Unsigned>::NonZero,
+}
+
+/// PostgreSQL-compatible unsigned 8-bit integer which is known not to be zero
+pub type PgNonZeroU8 = PgNonZeroUint<u8>;
+/// PostgreSQL-compatible unsigned 16-bit integer which is known not to be zero
+pub type PgNonZeroU16 = PgNonZeroUint<u16>;
+/// PostgreSQL-compatible unsigned 32-bit integer which is known not to be zero
+pub type PgNonZeroU32 = PgNonZeroUint<u32>;
+/// PostgreSQL-compatible unsigned 64-bit integer which is known not to be zero
+pub type PgNonZeroU64 = PgNonZeroUint<u64>;
+/// PostgreSQL-compatible unsigned 128-bit integer which is known not to be zero
+pub type PgNonZeroU128 = PgNonZeroUint<u128>;
+/// PostgreSQL-compatible unsigned 256-bit integer which is known not to be zero
+///
+/// There is no `std::num` type for [`U256`], so `Option<PgNonZeroU256>` is larger than
+/// `PgNonZeroU256`.
+#[cfg(feature = "u256")]
+pub type PgNonZeroU256 = PgNonZeroUint<U256>;
+
+impl<T: UIntType> PgNonZeroUint<T> {
+    /// Creates a new instance of this type, returning `None` if `num` is zero.
+    pub fn new(num: T) -> Option<Self> {
+        num.to_non_zero().map(|inner| Self { inner })
+    }
+
+    /// Converts this type to the associated unsigned integer type
+    pub fn to_uint(&self) -> T {
+        T::from_non_zero(self.inner)
+    }
+
+    /// Converts this type to the `PgUint` type of the same width
+    pub fn to_pg_uint(&self) -> PgUint<T> {
+        PgUint::new(self.to_uint())
+    }
+
+    /// Converts this type to a `BigDecimal`
+    pub fn to_big_decimal(&self) -> BigDecimal {
+        self.to_uint().to_big_decimal()
+    }
+}
+
+impl<T: UIntType> std::fmt::Display for PgNonZeroUint<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+impl<T: UIntType> std::fmt::Debug for PgNonZeroUint<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.inner, f)
+    }
+}
+
+impl<T: UIntType> std::str::FromStr for PgNonZeroUint<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let pg_uint = s
+            .parse::<PgUint<T>>()
+            .map_err(|error| error.with_type_name(T::NON_ZERO_NAME))?;
+        Self::try_from(pg_uint)
+    }
+}
+
+impl<T: UIntType> TryFrom<PgUint<T>> for PgNonZeroUint<T> {
+    type Error = Error;
+
+    fn try_from(value: PgUint<T>) -> Result<Self, Self::Error> {
+        Self::new(value.to_uint()).ok_or(Error::Zero {
+            type_name: T::NON_ZERO_NAME,
+            value: value.to_big_decimal(),
+        })
+    }
+}
+
+impl<T: UIntType> From<PgNonZeroUint<T>> for PgUint<T> {
+    fn from(value: PgNonZeroUint<T>) -> Self {
+        value.to_pg_uint()
+    }
+}
+
+impl<T: UIntType> TryFrom<BigDecimal> for PgNonZeroUint<T> {
+    type Error = Error;
+
+    fn try_from(value: BigDecimal) -> Result<Self, Self::Error> {
+        let pg_uint =
+            PgUint::<T>::try_from(value).map_err(|error| error.with_type_name(T::NON_ZERO_NAME))?;
+        Self::try_from(pg_uint)
+    }
+}
+
+impl<T: UIntType> From<PgNonZeroUint<T>> for BigDecimal {
+    fn from(value: PgNonZeroUint<T>) -> Self {
+        value.to_big_decimal()
+    }
+}
+
+macro_rules! impl_non_zero_conversions {
+    ($($uint:ty => $non_zero:ty),*) => {
+        $(
+            impl From<$non_zero> for PgNonZeroUint<$uint> {
+                fn from(value: $non_zero) -> Self {
+                    Self { inner: value }
+                }
+            }
+
+            impl From<PgNonZeroUint<$uint>> for $non_zero {
+                fn from(value: PgNonZeroUint<$uint>) -> Self {
+                    value.inner
+                }
+            }
+
+            impl TryFrom<$uint> for PgNonZeroUint<$uint> {
+                type Error = Error;
+
+                fn try_from(value: $uint) -> Result<Self, Self::Error> {
+                    Self::try_from(PgUint::new(value))
+                }
+            }
+
+            impl From<PgNonZeroUint<$uint>> for $uint {
+                fn from(value: PgNonZeroUint<$uint>) -> Self {
+                    value.to_uint()
+                }
+            }
+        )*
+    };
+}
+
+impl_non_zero_conversions!(
+    u8 => NonZeroU8,
+    u16 => NonZeroU16,
+    u32 => NonZeroU32,
+    u64 => NonZeroU64,
+    u128 => NonZeroU128
+);
+
+impl<T: UIntType> sqlx::Type<sqlx::Postgres> for PgNonZeroUint<T> {
+    fn type_info() -> <sqlx::Postgres as sqlx::Database>::TypeInfo {
+        <BigDecimal as sqlx::Type<sqlx::Postgres>>::type_info()
+    }
+}
+
+impl<'q, T: UIntType> sqlx::Encode<'q, sqlx::Postgres> for PgNonZeroUint<T> {
+    fn encode_by_ref(
+        &self,
+        buf: &mut <sqlx::Postgres as sqlx::Database>::ArgumentBuffer<'q>,
+    ) -> Result<sqlx::encode::IsNull, sqlx::error::BoxDynError> {
+        <BigDecimal as sqlx::Encode<sqlx::Postgres>>::encode_by_ref(&self.to_big_decimal(), buf)
+    }
+}
+
+impl<'r, T: UIntType> sqlx::Decode<'r, sqlx::Postgres> for PgNonZeroUint<T> {
+    fn decode(
+        value: <sqlx::Postgres as sqlx::Database>::ValueRef<'r>,
+    ) -> Result<Self, sqlx::error::BoxDynError> {
+        let big_decimal = crate::decode_big_decimal(value, T::NON_ZERO_NAME)?;
+        Ok(Self::try_from(big_decimal)?)
+    }
+}
+
+impl<T: UIntType> sqlx::postgres::PgHasArrayType for PgNonZeroUint<T> {
+    fn array_type_info() -> sqlx::postgres::PgTypeInfo {
+        <Vec<BigDecimal> as sqlx::Type<sqlx::Postgres>>::type_info()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: UIntType> serde::ser::Serialize for PgNonZeroUint<T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.to_pg_uint().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: UIntType> serde::de::Deserialize<'de> for PgNonZeroUint<T> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let big_decimal = BigDecimal::deserialize(deserializer)?;
+        Self::try_from(big_decimal).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod pg_non_zero_tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(PgNonZeroU64::new(42).unwrap().to_uint(), 42);
+        assert_eq!(PgNonZeroU8::new(u8::MAX).unwrap().to_uint(), u8::MAX);
+        assert_eq!(PgNonZeroU128::new(0), None);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let non_zero = NonZeroU64::new(7).unwrap();
+        let pg_non_zero = PgNonZeroU64::from(non_zero);
+        assert_eq!(NonZeroU64::from(pg_non_zero), non_zero);
+        assert_eq!(u64::from(pg_non_zero), 7);
+        assert_eq!(PgU64::from(pg_non_zero), PgU64::from(7u64));
+        assert_eq!(
+            PgNonZeroU64::try_from(PgU64::from(7u64)).unwrap(),
+            pg_non_zero
+        );
+        assert_eq!(
+            PgNonZeroU64::try_from(PgU64::from(0u64)).unwrap_err(),
+            Error::Zero {
+                type_name: "PgNonZeroU64",
+                value: BigDecimal::from(0)
+            }
+        );
+        assert_eq!(
+            PgNonZeroU16::try_from(0u16).unwrap_err(),
+            Error::Zero {
+                type_name: "PgNonZeroU16",
+                value: BigDecimal::from(0)
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_bigdecimal() {
+        let pg_non_zero = PgNonZeroU32::try_from(BigDecimal::from(12)).unwrap();
+        assert_eq!(pg_non_zero.to_uint(), 12);
+        assert_eq!(pg_non_zero.to_big_decimal(), BigDecimal::from(12));
+        assert_eq!(
+            PgNonZeroU32::try_from(BigDecimal::from(0)).unwrap_err(),
+            Error::Zero {
+                type_name: "PgNonZeroU32",
+                value: BigDecimal::from(0)
+            }
+        );
+        assert_eq!(
+            PgNonZeroU32::try_from(BigDecimal::from(-1)).unwrap_err(),
+            Error::Negative {
+                type_name: "PgNonZeroU32",
+                value: BigDecimal::from(-1)
+            }
+        );
+        assert_eq!(
+            PgNonZeroU32::try_from(BigDecimal::new(15.into(), 1)).unwrap_err(),
+            Error::Fractional {
+                type_name: "PgNonZeroU32",
+                value: BigDecimal::new(15.into(), 1)
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(PgNonZeroU8::from_str("5").unwrap().to_string(), "5");
+        assert_eq!(
+            PgNonZeroU8::from_str("0").unwrap_err(),
+            Error::Zero {
+                type_name: "PgNonZeroU8",
+                value: BigDecimal::from(0)
+            }
+        );
+        assert_eq!(
+            PgNonZeroU8::from_str("256").unwrap_err(),
+            Error::Parse {
+                type_name: "PgNonZeroU8",
+                input: "256".to_owned(),
+                kind: IntErrorKind::PosOverflow
+            }
+        );
+    }
+
+    #[test]
+    fn test_debug() {
+        let pg_non_zero = PgNonZeroU64::new(255).unwrap();
+        assert_eq!(format!("{:?}", pg_non_zero), "255");
+        assert_eq!(format!("{:?}", Some(pg_non_zero)), "Some(255)");
+        assert_eq!(format!("{:5?}", pg_non_zero), "  255");
+        assert_eq!(format!("{:#?}", pg_non_zero), "255");
+    }
+
+    #[test]
+    fn test_niche() {
+        assert_eq!(
+            std::mem::size_of::<Option<PgNonZeroU64>>(),
+            std::mem::size_of::<PgNonZeroU64>()
+        );
+        assert_eq!(
+            std::mem::size_of::<Option<PgNonZeroU128>>(),
+            std::mem::size_of::<u128>()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        assert_tokens(&PgNonZeroU64::new(42).unwrap(), &[Token::U64(42)]);
+        assert_de_tokens_error::<PgNonZeroU64>(
+            &[Token::U64(0)],
+            "0 is zero, which is not allowed for PgNonZeroU64",
+        );
+        assert_de_tokens_error::<PgNonZeroU8>(
+            &[Token::U64(256)],
+            "256 is larger than 255, the maximum of PgNonZeroU8",
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use super::*;
+
+/// The largest exponent accepted in scientific notation. No supported integer type has anywhere
+/// near this many digits, and larger exponents would make `BigDecimal` materialize huge numbers.
+const MAX_EXPONENT: i64 = 100;
+
+/// A string prepared for lenient parsing by [`lenient`].
+#[derive(Debug, PartialEq)]
+pub(crate) enum Lenient {
+    /// Digits in the given radix, with an optional leading `-`
+    Radix(String, u32),
+    /// A decimal number, which may have been written in scientific notation
+    Decimal(BigDecimal),
+}
+
+/// Prepares `src` for lenient parsing.
+///
+/// Surrounding whitespace and `_` separators are removed, and a leading `+` or `-` sign is
+/// accepted. `0x`, `0o` and `0b` prefixes select the radix, anything else is parsed as a decimal
+/// number, which may use scientific notation such as `1e6`.
+pub(crate) fn lenient(src: &str) -> Result<Lenient, IntErrorKind> {
+    let cleaned = src.trim().replace('_', "");
+    let (sign, unsigned) = match cleaned.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", cleaned.strip_prefix('+').unwrap_or(&cleaned)),
+    };
+    if unsigned.is_empty() {
+        return Err(IntErrorKind::Empty);
+    }
+
+    let prefix = unsigned.get(..2).map(str::to_ascii_lowercase);
+    let radix = match prefix.as_deref() {
+        Some("0x") => Some(16),
+        Some("0o") => Some(8),
+        Some("0b") => Some(2),
+        _ => None,
+    };
+    if let (Some(radix), Some(digits)) = (radix, unsigned.get(2..)) {
+        return Ok(Lenient::Radix(format!("{}{}", sign, digits), radix));
+    }
+
+    if let Some((_, exponent)) = unsigned.split_once(['e', 'E']) {
+        let exponent: i64 = exponent.parse().map_err(|_| IntErrorKind::InvalidDigit)?;
+        if exponent > MAX_EXPONENT {
+            return Err(IntErrorKind::PosOverflow);
+        }
+        if exponent < -MAX_EXPONENT {
+            return Err(IntErrorKind::InvalidDigit);
+        }
+    }
+    // `BigDecimal` accepts a few spellings we do not want, such as a leading `.`.
+    if !unsigned.starts_with(|c: char| c.is_ascii_digit()) {
+        return Err(IntErrorKind::InvalidDigit);
+    }
+    format!("{}{}", sign, unsigned)
+        .parse()
+        .map(Lenient::Decimal)
+        .map_err(|_| IntErrorKind::InvalidDigit)
+}
+
+#[cfg(test)]
+mod parse_tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_lenient() {
+        assert_eq!(
+            lenient(" 0xFFFF_FFFF\n"),
+            Ok(Lenient::Radix("FFFFFFFF".to_owned(), 16))
+        );
+        assert_eq!(lenient("-0b101"), Ok(Lenient::Radix("-101".to_owned(), 2)));
+        assert_eq!(lenient("0O17"), Ok(Lenient::Radix("17".to_owned(), 8)));
+        assert_eq!(
+            lenient("+1_000_000"),
+            Ok(Lenient::Decimal(BigDecimal::from(1_000_000)))
+        );
+        assert_eq!(
+            lenient("1e6"),
+            Ok(Lenient::Decimal(BigDecimal::from_str("1e6").unwrap()))
+        );
+        assert_eq!(lenient("  "), Err(IntErrorKind::Empty));
+        assert_eq!(lenient("-"), Err(IntErrorKind::Empty));
+        assert_eq!(lenient("abc"), Err(IntErrorKind::InvalidDigit));
+        assert_eq!(lenient(".5"), Err(IntErrorKind::InvalidDigit));
+        assert_eq!(lenient("1e1000"), Err(IntErrorKind::PosOverflow));
+        assert_eq!(lenient("1e-1000"), Err(IntErrorKind::InvalidDigit));
+    }
+}
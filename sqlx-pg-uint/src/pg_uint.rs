@@ -0,0 +1,300 @@
+use super::*;
+use sqlx_pg_uint_macros::UIntWrapper;
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord, UIntWrapper, sqlx::FromRow)]
+#[uint_wrapper(u8, u16, u32, u64, u128)]
+#[cfg_attr(feature = "u256", uint_wrapper(U256))]
+/// PostgreSQL-compatible unsigned integer, generic over the underlying integer type
+///
+/// Every constructor stores the plain integer, whatever scale or exponent a `BigDecimal` or string
+/// was written with, so `Eq`, `Ord` and `Hash` agree with the numeric value.
+///
+/// Usually used through one of its aliases, such as [`PgU64`]. Generic code which works with any
+/// unsigned column can take a `PgUint<T>` with a `T: UIntType` bound:
+///
+/// ```
+/// use sqlx_pg_uint::{PgU16, PgU64, PgUint, UIntType};
+///
+/// fn double<T: UIntType>(value: PgUint<T>) -> PgUint<T> {
+///     value + value
+/// }
+///
+/// assert_eq!(double(PgU16::from(21u16)), 42u16);
+/// assert_eq!(double(PgU64::from(21u64)), 42u64);
+/// ```
+pub struct PgUint<T: UIntType> {
+    inner: T,
+}
+
+impl<T: UIntType> private::Sealed for PgUint<T> {}
+
+/// Common interface of the `PgUint` types, for code which is generic over the column type
+///
+/// Implemented by `PgUint<T>` for every [`UIntType`], that is [`PgU8`], [`PgU16`], [`PgU32`],
+/// [`PgU64`], [`PgU128`] and, with the `u256` feature, `PgU256`, and sealed so it cannot be
+/// implemented outside of this crate. Where [`UIntType`] bounds the integer type, this trait bounds
+/// the column type itself, and includes the `sqlx` traits needed to bind and fetch it, on its own
+/// or as an array. It is not named `PgUint`, as that name belongs to the generic struct.
+///
+/// ```
+/// use sqlx::postgres::{PgArguments, Postgres};
+/// use sqlx::query::Query;
+/// use sqlx_pg_uint::{PgU32, PgU64, PgUnsigned};
+///
+/// fn delete_by_id<'q, Id: PgUnsigned>(id: Id) -> Query<'q, Postgres, PgArguments> {
+///     sqlx::query("DELETE FROM items WHERE id = $1").bind(id)
+/// }
+///
+/// fn delete_by_ids<'q, Id: PgUnsigned>(ids: Vec<Id>) -> Query<'q, Postgres, PgArguments> {
+///     sqlx::query("DELETE FROM items WHERE id = ANY($1)").bind(ids)
+/// }
+///
+/// fn next_id<Id: PgUnsigned>(last: Id) -> Option<Id> {
+///     last.checked_add(Id::ONE)
+/// }
+///
+/// let _ = delete_by_id(PgU32::from(1u32));
+/// let _ = delete_by_ids(vec![PgU32::from(1u32), PgU32::from(2u32)]);
+/// assert_eq!(next_id(PgU64::from(41u64)), Some(PgU64::from(42u64)));
+/// assert_eq!(next_id(PgU64::MAX), None);
+/// ```
+pub trait PgUnsigned:
+    private::Sealed
+    + Copy
+    + Ord
+    + std::hash::Hash
+    + std::fmt::Debug
+    + std::fmt::Display
+    + Default
+    + std::str::FromStr<Err = Error>
+    + TryFrom<BigDecimal, Error = Error>
+    + Into<BigDecimal>
+    + sqlx::Type<sqlx::Postgres>
+    + for<'q> sqlx::Encode<'q, sqlx::Postgres>
+    + for<'r> sqlx::Decode<'r, sqlx::Postgres>
+    + sqlx::postgres::PgHasArrayType
+    + Send
+    + Sync
+    + 'static
+{
+    /// The underlying unsigned integer type
+    type Uint: Copy
+        + Ord
+        + std::hash::Hash
+        + std::fmt::Debug
+        + std::fmt::Display
+        + Send
+        + Sync
+        + 'static;
+
+    /// The smallest value that can be represented by this type.
+    const MIN: Self;
+    /// The largest value that can be represented by this type.
+    const MAX: Self;
+    /// The value zero.
+    const ZERO: Self;
+    /// The value one.
+    const ONE: Self;
+    /// The size of the underlying integer type in bits.
+    const BITS: u32;
+
+    /// Creates a new instance of this type from the underlying integer type
+    fn new(num: Self::Uint) -> Self;
+    /// Converts this type to the underlying integer type
+    fn to_uint(&self) -> Self::Uint;
+    /// Converts this type to a `BigDecimal`
+    fn to_big_decimal(&self) -> BigDecimal;
+
+    /// Checked integer addition. Returns `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Checked integer subtraction. Returns `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Checked integer multiplication. Returns `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Checked integer division. Returns `None` if `rhs` is zero.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+    /// Checked integer remainder. Returns `None` if `rhs` is zero.
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+}
+
+impl<T: UIntType> PgUnsigned for PgUint<T> {
+    type Uint = T;
+
+    const MIN: Self = Self::MIN;
+    const MAX: Self = Self::MAX;
+    const ZERO: Self = Self::ZERO;
+    const ONE: Self = Self::ONE;
+    const BITS: u32 = Self::BITS;
+
+    fn new(num: T) -> Self {
+        Self::new(num)
+    }
+
+    fn to_uint(&self) -> T {
+        self.to_uint()
+    }
+
+    fn to_big_decimal(&self) -> BigDecimal {
+        self.to_big_decimal()
+    }
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs)
+    }
+
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_sub(rhs)
+    }
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs)
+    }
+
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_div(rhs)
+    }
+
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        self.checked_rem(rhs)
+    }
+}
+
+#[cfg(test)]
+mod pg_uint_tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn try_from_bigdecimal_huge_exponent() {
+        // These are rejected without materializing a number with as many digits as the exponent
+        let huge = BigDecimal::from_str("1e100000000").unwrap();
+        assert_eq!(
+            PgU8::try_from(huge.clone()).unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU8",
+                value: huge.clone(),
+                max: BigDecimal::from(u8::MAX)
+            }
+        );
+        assert!(PgU128::try_from(huge).is_err());
+        let negative = BigDecimal::from_str("-1e100000000").unwrap();
+        assert!(matches!(
+            PgU64::try_from(negative.clone()),
+            Err(Error::Negative { .. })
+        ));
+        assert!(matches!(
+            PgI128::try_from(negative),
+            Err(Error::TooSmall { .. })
+        ));
+        let tiny = BigDecimal::from_str("1e-100000000").unwrap();
+        assert!(matches!(
+            PgU32::try_from(tiny),
+            Err(Error::Fractional { .. })
+        ));
+        let zero = BigDecimal::from_str("0e-100000000").unwrap();
+        assert_eq!(PgU16::try_from(zero).unwrap(), 0u16);
+        // The largest magnitude which passes the check is still range-checked precisely
+        assert_eq!(PgU8::try_from(BigDecimal::from(255)).unwrap(), u8::MAX);
+        assert!(PgU8::try_from(BigDecimal::from(999)).is_err());
+        assert!(PgU8::try_from(BigDecimal::from(1000)).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_huge_exponent() {
+        use serde_test::{assert_de_tokens_error, Token};
+
+        assert_de_tokens_error::<PgU8>(
+            &[Token::Str("1e10000000")],
+            "1e+10000000 is larger than 255, the maximum of PgU8",
+        );
+    }
+
+    #[test]
+    fn test_isqrt() {
+        for n in 0..=u16::MAX {
+            let root = PgU16::from(n).isqrt().to_uint() as u32;
+            let n = n as u32;
+            assert!(
+                root * root <= n && (root + 1) * (root + 1) > n,
+                "isqrt({n}) = {root}"
+            );
+        }
+        assert_eq!(PgU8::from(u8::MAX).isqrt(), 15u8);
+        assert_eq!(PgU128::from(u128::MAX).isqrt(), u64::MAX as u128);
+    }
+
+    #[test]
+    fn test_consts() {
+        const THRESHOLD: PgU64 = PgU64::new(1_000);
+        static LIMIT: PgU64 = PgU64::MAX;
+        const RAW: u64 = THRESHOLD.get();
+        assert_eq!(RAW, 1_000);
+        assert_eq!(LIMIT, u64::MAX);
+        assert_eq!(PgU64::MIN, 0u64);
+        assert_eq!(PgU64::ZERO, 0u64);
+        assert_eq!(PgU64::ONE, 1u64);
+        assert_eq!(PgU64::BITS, 64);
+        assert_eq!(PgU8::NUMERIC_PRECISION, 3);
+        assert_eq!(PgU16::NUMERIC_PRECISION, 5);
+        assert_eq!(PgU32::NUMERIC_PRECISION, 10);
+        assert_eq!(PgU64::NUMERIC_PRECISION, 20);
+        assert_eq!(PgU128::NUMERIC_PRECISION, 39);
+        // The precision is the number of digits of the largest value
+        assert_eq!(
+            PgU64::MAX.to_string().len() as u32,
+            PgU64::NUMERIC_PRECISION
+        );
+        assert_eq!(
+            PgU128::MAX.to_string().len() as u32,
+            PgU128::NUMERIC_PRECISION
+        );
+    }
+}
+
+#[cfg(test)]
+mod pg_unsigned_tests {
+    use super::*;
+
+    fn sum_all<U: PgUnsigned>(values: &[U]) -> Option<U> {
+        values
+            .iter()
+            .try_fold(U::ZERO, |acc, value| acc.checked_add(*value))
+    }
+
+    fn describe<U: PgUnsigned>(value: U) -> (U::Uint, u32, bool) {
+        (value.to_uint(), U::BITS, value == U::MAX)
+    }
+
+    fn select_by_ids<U: PgUnsigned>(
+        ids: &[U],
+    ) -> sqlx::query::Query<'_, sqlx::Postgres, sqlx::postgres::PgArguments> {
+        sqlx::query("SELECT * FROM items WHERE id = ANY($1)")
+            .bind(ids)
+            .bind(ids.to_vec())
+    }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(
+            sum_all(&[PgU8::from(1u8), PgU8::from(2u8)]),
+            Some(PgU8::from(3u8))
+        );
+        assert_eq!(sum_all(&[PgU8::MAX, PgU8::ONE]), None);
+        assert_eq!(describe(PgU16::MAX), (u16::MAX, 16, true));
+        assert_eq!(describe(PgU128::from(5u128)), (5, 128, false));
+        assert_eq!(<PgU32 as PgUnsigned>::MIN, 0u32);
+        assert_eq!(
+            <PgU64 as PgUnsigned>::new(7).to_big_decimal(),
+            BigDecimal::from(7)
+        );
+        assert_eq!(PgU64::from(7u64).checked_div(PgU64::ZERO), None);
+        assert_eq!(
+            PgUnsigned::checked_rem(PgU64::from(7u64), PgU64::from(4u64)),
+            Some(PgU64::from(3u64))
+        );
+        assert_eq!(PgUnsigned::checked_rem(PgU8::ONE, PgU8::ZERO), None);
+        let _ = select_by_ids(&[PgU32::from(1u32), PgU32::from(2u32)]);
+    }
+}
@@ -1,37 +1,12 @@
 use super::*;
-use sqlx_pg_uint_macros::UIntWrapper;
 
-use crate::UIntType;
-
-impl private::Sealed for PgU128 {}
-
-impl UIntType for PgU128 {
-    type Uint = u128;
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, UIntWrapper, sqlx::FromRow)]
 /// PostgreSQL-compatible unsigned 128-bit integer
-pub struct PgU128 {
-    inner: BigDecimal,
-}
-
-impl From<PgU128> for u128 {
-    fn from(value: PgU128) -> Self {
-        value.inner.to_string().parse().unwrap()
-    }
-}
-
-impl From<u128> for PgU128 {
-    fn from(value: u128) -> Self {
-        Self {
-            inner: BigDecimal::from(value),
-        }
-    }
-}
+pub type PgU128 = PgUint<u128>;
 
 #[cfg(test)]
 mod pg_u128_tests {
     use bigdecimal::num_bigint::BigInt;
+    use std::str::FromStr;
 
     use super::*;
 
@@ -39,7 +14,7 @@ mod pg_u128_tests {
     fn test_to_u128() {
         let pg_u128 = PgU128::from(12678671u128);
         assert_eq!(pg_u128.to_uint(), 12678671u128);
-        let pg_u128 = PgU128::from(0);
+        let pg_u128 = PgU128::from(0u128);
         assert_eq!(pg_u128.to_uint(), 0u128);
         let pg_u128 = PgU128::from(u128::MAX);
         assert_eq!(pg_u128.to_uint(), u128::MAX);
@@ -72,6 +47,203 @@ mod pg_u128_tests {
         let _ = pg_u128 - pg_u1282;
     }
 
+    #[test]
+    fn test_checked_ops() {
+        let max = PgU128::from(u128::MAX);
+        let one = PgU128::from(1u128);
+        let zero = PgU128::from(0u128);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(zero.checked_sub(one), None);
+        assert_eq!(max.checked_mul(PgU128::from(2u128)), None);
+        assert_eq!(one.checked_div(zero), None);
+        assert_eq!(one.checked_rem(zero), None);
+        assert_eq!(one.checked_add(one), Some(PgU128::from(2u128)));
+        assert_eq!(max.checked_div(max), Some(one));
+        assert_eq!(max.checked_rem(max), Some(zero));
+    }
+
+    #[test]
+    fn test_wrapping_ops() {
+        let max = PgU128::from(u128::MAX);
+        let one = PgU128::from(1u128);
+        let zero = PgU128::from(0u128);
+        assert_eq!(max.wrapping_add(one), zero);
+        assert_eq!(zero.wrapping_sub(one), max);
+        assert_eq!(
+            max.wrapping_mul(PgU128::from(2u128)),
+            PgU128::from(u128::MAX - 1)
+        );
+        assert_eq!(max.wrapping_div(one), max);
+        assert_eq!(max.wrapping_rem(PgU128::from(2u128)), one);
+    }
+
+    #[test]
+    fn test_saturating_ops() {
+        let max = PgU128::from(u128::MAX);
+        let one = PgU128::from(1u128);
+        let zero = PgU128::from(0u128);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(zero.saturating_sub(one), zero);
+        assert_eq!(max.saturating_mul(PgU128::from(2u128)), max);
+        assert_eq!(max.saturating_div(one), max);
+    }
+
+    #[test]
+    fn test_overflowing_ops() {
+        let max = PgU128::from(u128::MAX);
+        let one = PgU128::from(1u128);
+        let zero = PgU128::from(0u128);
+        assert_eq!(max.overflowing_add(one), (zero, true));
+        assert_eq!(zero.overflowing_sub(one), (max, true));
+        assert_eq!(one.overflowing_mul(max), (max, false));
+        assert_eq!(max.overflowing_div(one), (max, false));
+        assert_eq!(max.overflowing_rem(one), (zero, false));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_ref_ops() {
+        let a = PgU128::from(12u128);
+        let b = PgU128::from(5u128);
+        assert_eq!(&a + &b, PgU128::from(17u128));
+        assert_eq!(&a - b, PgU128::from(7u128));
+        assert_eq!(a * &b, PgU128::from(60u128));
+        assert_eq!(&a / &b, PgU128::from(2u128));
+        assert_eq!(&a % &b, PgU128::from(2u128));
+
+        let mut c = a;
+        c += &b;
+        c %= b;
+        c %= &a;
+        assert_eq!(c, PgU128::from(2u128));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_bitwise_ops() {
+        let a = PgU128::from(0b1100u128);
+        let b = PgU128::from(0b1010u128);
+        assert_eq!(a & b, PgU128::from(0b1000u128));
+        assert_eq!(&a | &b, PgU128::from(0b1110u128));
+        assert_eq!(a ^ &b, PgU128::from(0b0110u128));
+        assert_eq!(!PgU128::from(0u128), PgU128::from(u128::MAX));
+        assert_eq!(!&a, PgU128::from(!0b1100u128));
+        assert_eq!(a << 2, PgU128::from(0b110000u128));
+        assert_eq!(&a >> 2, PgU128::from(0b11u128));
+        assert_eq!(a & 0b0100, PgU128::from(0b0100u128));
+
+        let mut c = a;
+        c |= b;
+        c &= &a;
+        c ^= 0b0001;
+        c <<= 1;
+        c >>= 2;
+        assert_eq!(c, PgU128::from(0b0110u128));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values = vec![
+            PgU128::from(2u128),
+            PgU128::from(3u128),
+            PgU128::from(4u128),
+        ];
+        assert_eq!(values.iter().sum::<PgU128>(), PgU128::from(9u128));
+        assert_eq!(values.iter().product::<PgU128>(), PgU128::from(24u128));
+        assert_eq!(
+            values.clone().into_iter().sum::<PgU128>(),
+            PgU128::from(9u128)
+        );
+        assert_eq!(
+            values.clone().into_iter().product::<PgU128>(),
+            PgU128::from(24u128)
+        );
+        assert_eq!(
+            Vec::<PgU128>::new().into_iter().sum::<PgU128>(),
+            PgU128::from(0u128)
+        );
+        assert_eq!(
+            Vec::<PgU128>::new().into_iter().product::<PgU128>(),
+            PgU128::from(1u128)
+        );
+
+        assert_eq!(PgU128::checked_sum(&values), Some(PgU128::from(9u128)));
+        assert_eq!(PgU128::checked_product(values), Some(PgU128::from(24u128)));
+
+        let overflowing = [PgU128::from(u128::MAX), PgU128::from(2u128)];
+        assert_eq!(PgU128::checked_sum(overflowing), None);
+        assert_eq!(PgU128::checked_product(overflowing.iter()), None);
+    }
+
+    #[test]
+    fn test_primitive_ops() {
+        let pg_u128 = PgU128::from(12u128);
+        assert_eq!(pg_u128 + 3, PgU128::from(15u128));
+        assert_eq!(pg_u128 - 3, PgU128::from(9u128));
+        assert_eq!(pg_u128 * 3, PgU128::from(36u128));
+        assert_eq!(pg_u128 / 5, PgU128::from(2u128));
+        assert_eq!(pg_u128 % 5, PgU128::from(2u128));
+        assert_eq!(3 + pg_u128, PgU128::from(15u128));
+        assert_eq!(20 - pg_u128, PgU128::from(8u128));
+        assert_eq!(3 * pg_u128, PgU128::from(36u128));
+        assert_eq!(36 / pg_u128, PgU128::from(3u128));
+        assert_eq!(17 % pg_u128, PgU128::from(5u128));
+
+        let mut pg_u128 = PgU128::from(12u128);
+        pg_u128 += 8;
+        pg_u128 -= 4;
+        pg_u128 *= 3;
+        pg_u128 /= 2;
+        pg_u128 %= 7;
+        assert_eq!(pg_u128, PgU128::from(3u128));
+    }
+
+    #[test]
+    fn test_primitive_cmp() {
+        let pg_u128 = PgU128::from(12u128);
+        assert_eq!(pg_u128, 12u128);
+        assert_eq!(12u128, pg_u128);
+        assert_ne!(pg_u128, 13u128);
+        assert!(pg_u128 < 13u128);
+        assert!(pg_u128 > 11u128);
+        assert!(11u128 < pg_u128);
+        assert!(u128::MAX > pg_u128);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{
+            Bounded, CheckedAdd, FromPrimitive, Num, One, Saturating, ToPrimitive, Zero,
+        };
+
+        assert_eq!(PgU128::zero(), PgU128::from(0u128));
+        assert!(PgU128::zero().is_zero());
+        assert_eq!(PgU128::one(), PgU128::from(1u128));
+        assert_eq!(PgU128::min_value(), PgU128::from(u128::MIN));
+        assert_eq!(PgU128::max_value(), PgU128::from(u128::MAX));
+        assert_eq!(
+            <PgU128 as Num>::from_str_radix("ff", 16),
+            Ok(PgU128::from(255u128))
+        );
+        assert_eq!(
+            CheckedAdd::checked_add(&PgU128::max_value(), &PgU128::one()),
+            None
+        );
+        assert_eq!(
+            Saturating::saturating_add(PgU128::max_value(), PgU128::one()),
+            PgU128::max_value()
+        );
+        assert_eq!(PgU128::from(200u128).to_u8(), Some(200u8));
+        assert_eq!(PgU128::max_value().to_u128(), Some(u128::MAX));
+        assert_eq!(PgU128::from_i64(-1), None);
+        assert_eq!(PgU128::from_u64(200), Some(PgU128::from(200u128)));
+        assert_eq!(
+            num_traits::clamp(PgU128::from(200u128), PgU128::zero(), PgU128::from(100u128)),
+            PgU128::from(100u128)
+        );
+    }
+
     #[test]
     fn try_from_bigdecimal() {
         let pg_u128 = PgU128::try_from(BigDecimal::from(12678671u128)).unwrap();
@@ -86,24 +258,84 @@ mod pg_u128_tests {
         let pg_u128 = PgU128::try_from(BigDecimal::from(-1));
         assert!(pg_u128.is_err());
         let err = pg_u128.unwrap_err();
-        assert_eq!(err, Error::InvalidValue(BigDecimal::from(-1)));
+        assert_eq!(
+            err,
+            Error::Negative {
+                type_name: "PgU128",
+                value: BigDecimal::from(-1)
+            }
+        );
 
         let fractional = BigDecimal::from(3) / BigDecimal::from(2);
         let pg_u128 = PgU128::try_from(fractional.clone());
-        assert_eq!(pg_u128.unwrap_err(), Error::Fractional(fractional));
+        assert_eq!(
+            pg_u128.unwrap_err(),
+            Error::Fractional {
+                type_name: "PgU128",
+                value: fractional
+            }
+        );
 
         let big_decimal = BigDecimal::from(BigInt::from(2).pow(128));
         let pg_u128 = PgU128::try_from(big_decimal.clone());
-        assert_eq!(pg_u128.unwrap_err(), Error::InvalidValue(big_decimal));
+        assert_eq!(
+            pg_u128.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU128",
+                value: big_decimal,
+                max: BigDecimal::from(u128::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_big_decimal() {
+        let pg_u128 = PgU128::from(u128::MAX);
+        assert_eq!(pg_u128.to_big_decimal(), BigDecimal::from(u128::MAX));
+        assert_eq!(BigDecimal::from(pg_u128), BigDecimal::from(u128::MAX));
+        assert_eq!(PgU128::try_from(pg_u128.to_big_decimal()).unwrap(), pg_u128);
+    }
+
+    #[test]
+    fn try_from_bigdecimal_out_of_range() {
+        let too_large = BigDecimal::from(u128::MAX) + BigDecimal::from(1);
+        let pg_u128 = PgU128::try_from(too_large.clone());
+        assert_eq!(
+            pg_u128.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU128",
+                value: too_large,
+                max: BigDecimal::from(u128::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_bigdecimal_with_scale() {
+        let scaled = BigDecimal::from_str("5.00").unwrap();
+        assert_eq!(PgU128::try_from(scaled).unwrap().to_uint(), 5u128);
+
+        let trailing_zeros = BigDecimal::from_str("120.000").unwrap();
+        assert_eq!(PgU128::try_from(trailing_zeros).unwrap().to_uint(), 120u128);
+
+        let exponent = BigDecimal::from_str("1E+2").unwrap();
+        assert_eq!(PgU128::try_from(exponent).unwrap().to_uint(), 100u128);
+
+        let negative_scale = BigDecimal::new(BigInt::from(12), -1);
+        assert_eq!(PgU128::try_from(negative_scale).unwrap().to_uint(), 120u128);
+
+        let max = BigDecimal::from(u128::MAX).with_scale(2);
+        assert_eq!(PgU128::try_from(max).unwrap().to_uint(), u128::MAX);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_option_conversion() {
         let somepguint = Some(PgU128::from(123u128));
         let someuint = somepguint.to_option_uint();
         assert_eq!(someuint, Some(123u128));
 
-        let pguint = PgU128::from(123);
+        let pguint = PgU128::from(123u128);
         let someuint = pguint.to_option_uint();
         assert_eq!(someuint, Some(123u128));
 
@@ -1,37 +1,12 @@
 use super::*;
-use sqlx_pg_uint_macros::UIntWrapper;
 
-use crate::UIntType;
-
-impl UIntType for PgU16 {
-    type Uint = u16;
-}
-
-impl private::Sealed for PgU16 {}
-
-#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, UIntWrapper, sqlx::FromRow)]
 /// PostgreSQL-compatible unsigned 16-bit integer
-pub struct PgU16 {
-    inner: BigDecimal,
-}
-
-impl From<PgU16> for u16 {
-    fn from(value: PgU16) -> Self {
-        value.inner.to_string().parse().unwrap()
-    }
-}
-
-impl From<u16> for PgU16 {
-    fn from(value: u16) -> Self {
-        Self {
-            inner: BigDecimal::from(value),
-        }
-    }
-}
+pub type PgU16 = PgUint<u16>;
 
 #[cfg(test)]
 mod pg_u16_tests {
     use bigdecimal::num_bigint::BigInt;
+    use std::str::FromStr;
 
     use super::*;
 
@@ -39,7 +14,7 @@ mod pg_u16_tests {
     fn test_to_u16() {
         let pg_u16 = PgU16::from(121u16);
         assert_eq!(pg_u16.to_uint(), 121u16);
-        let pg_u16 = PgU16::from(0);
+        let pg_u16 = PgU16::from(0u16);
         assert_eq!(pg_u16.to_uint(), 0u16);
         let pg_u16 = PgU16::from(u16::MAX);
         assert_eq!(pg_u16.to_uint(), u16::MAX);
@@ -72,6 +47,196 @@ mod pg_u16_tests {
         let _ = pg_u16 - pg_u162;
     }
 
+    #[test]
+    fn test_checked_ops() {
+        let max = PgU16::from(u16::MAX);
+        let one = PgU16::from(1u16);
+        let zero = PgU16::from(0u16);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(zero.checked_sub(one), None);
+        assert_eq!(max.checked_mul(PgU16::from(2u16)), None);
+        assert_eq!(one.checked_div(zero), None);
+        assert_eq!(one.checked_rem(zero), None);
+        assert_eq!(one.checked_add(one), Some(PgU16::from(2u16)));
+        assert_eq!(max.checked_div(max), Some(one));
+        assert_eq!(max.checked_rem(max), Some(zero));
+    }
+
+    #[test]
+    fn test_wrapping_ops() {
+        let max = PgU16::from(u16::MAX);
+        let one = PgU16::from(1u16);
+        let zero = PgU16::from(0u16);
+        assert_eq!(max.wrapping_add(one), zero);
+        assert_eq!(zero.wrapping_sub(one), max);
+        assert_eq!(
+            max.wrapping_mul(PgU16::from(2u16)),
+            PgU16::from(u16::MAX - 1)
+        );
+        assert_eq!(max.wrapping_div(one), max);
+        assert_eq!(max.wrapping_rem(PgU16::from(2u16)), one);
+    }
+
+    #[test]
+    fn test_saturating_ops() {
+        let max = PgU16::from(u16::MAX);
+        let one = PgU16::from(1u16);
+        let zero = PgU16::from(0u16);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(zero.saturating_sub(one), zero);
+        assert_eq!(max.saturating_mul(PgU16::from(2u16)), max);
+        assert_eq!(max.saturating_div(one), max);
+    }
+
+    #[test]
+    fn test_overflowing_ops() {
+        let max = PgU16::from(u16::MAX);
+        let one = PgU16::from(1u16);
+        let zero = PgU16::from(0u16);
+        assert_eq!(max.overflowing_add(one), (zero, true));
+        assert_eq!(zero.overflowing_sub(one), (max, true));
+        assert_eq!(one.overflowing_mul(max), (max, false));
+        assert_eq!(max.overflowing_div(one), (max, false));
+        assert_eq!(max.overflowing_rem(one), (zero, false));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_ref_ops() {
+        let a = PgU16::from(12u16);
+        let b = PgU16::from(5u16);
+        assert_eq!(&a + &b, PgU16::from(17u16));
+        assert_eq!(&a - b, PgU16::from(7u16));
+        assert_eq!(a * &b, PgU16::from(60u16));
+        assert_eq!(&a / &b, PgU16::from(2u16));
+        assert_eq!(&a % &b, PgU16::from(2u16));
+
+        let mut c = a;
+        c += &b;
+        c %= b;
+        c %= &a;
+        assert_eq!(c, PgU16::from(2u16));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_bitwise_ops() {
+        let a = PgU16::from(0b1100u16);
+        let b = PgU16::from(0b1010u16);
+        assert_eq!(a & b, PgU16::from(0b1000u16));
+        assert_eq!(&a | &b, PgU16::from(0b1110u16));
+        assert_eq!(a ^ &b, PgU16::from(0b0110u16));
+        assert_eq!(!PgU16::from(0u16), PgU16::from(u16::MAX));
+        assert_eq!(!&a, PgU16::from(!0b1100u16));
+        assert_eq!(a << 2, PgU16::from(0b110000u16));
+        assert_eq!(&a >> 2, PgU16::from(0b11u16));
+        assert_eq!(a & 0b0100, PgU16::from(0b0100u16));
+
+        let mut c = a;
+        c |= b;
+        c &= &a;
+        c ^= 0b0001;
+        c <<= 1;
+        c >>= 2;
+        assert_eq!(c, PgU16::from(0b0110u16));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values = vec![PgU16::from(2u16), PgU16::from(3u16), PgU16::from(4u16)];
+        assert_eq!(values.iter().sum::<PgU16>(), PgU16::from(9u16));
+        assert_eq!(values.iter().product::<PgU16>(), PgU16::from(24u16));
+        assert_eq!(values.clone().into_iter().sum::<PgU16>(), PgU16::from(9u16));
+        assert_eq!(
+            values.clone().into_iter().product::<PgU16>(),
+            PgU16::from(24u16)
+        );
+        assert_eq!(
+            Vec::<PgU16>::new().into_iter().sum::<PgU16>(),
+            PgU16::from(0u16)
+        );
+        assert_eq!(
+            Vec::<PgU16>::new().into_iter().product::<PgU16>(),
+            PgU16::from(1u16)
+        );
+
+        assert_eq!(PgU16::checked_sum(&values), Some(PgU16::from(9u16)));
+        assert_eq!(PgU16::checked_product(values), Some(PgU16::from(24u16)));
+
+        let overflowing = [PgU16::from(u16::MAX), PgU16::from(2u16)];
+        assert_eq!(PgU16::checked_sum(overflowing), None);
+        assert_eq!(PgU16::checked_product(overflowing.iter()), None);
+    }
+
+    #[test]
+    fn test_primitive_ops() {
+        let pg_u16 = PgU16::from(12u16);
+        assert_eq!(pg_u16 + 3, PgU16::from(15u16));
+        assert_eq!(pg_u16 - 3, PgU16::from(9u16));
+        assert_eq!(pg_u16 * 3, PgU16::from(36u16));
+        assert_eq!(pg_u16 / 5, PgU16::from(2u16));
+        assert_eq!(pg_u16 % 5, PgU16::from(2u16));
+        assert_eq!(3 + pg_u16, PgU16::from(15u16));
+        assert_eq!(20 - pg_u16, PgU16::from(8u16));
+        assert_eq!(3 * pg_u16, PgU16::from(36u16));
+        assert_eq!(36 / pg_u16, PgU16::from(3u16));
+        assert_eq!(17 % pg_u16, PgU16::from(5u16));
+
+        let mut pg_u16 = PgU16::from(12u16);
+        pg_u16 += 8;
+        pg_u16 -= 4;
+        pg_u16 *= 3;
+        pg_u16 /= 2;
+        pg_u16 %= 7;
+        assert_eq!(pg_u16, PgU16::from(3u16));
+    }
+
+    #[test]
+    fn test_primitive_cmp() {
+        let pg_u16 = PgU16::from(12u16);
+        assert_eq!(pg_u16, 12u16);
+        assert_eq!(12u16, pg_u16);
+        assert_ne!(pg_u16, 13u16);
+        assert!(pg_u16 < 13u16);
+        assert!(pg_u16 > 11u16);
+        assert!(11u16 < pg_u16);
+        assert!(u16::MAX > pg_u16);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{
+            Bounded, CheckedAdd, FromPrimitive, Num, One, Saturating, ToPrimitive, Zero,
+        };
+
+        assert_eq!(PgU16::zero(), PgU16::from(0u16));
+        assert!(PgU16::zero().is_zero());
+        assert_eq!(PgU16::one(), PgU16::from(1u16));
+        assert_eq!(PgU16::min_value(), PgU16::from(u16::MIN));
+        assert_eq!(PgU16::max_value(), PgU16::from(u16::MAX));
+        assert_eq!(
+            <PgU16 as Num>::from_str_radix("ff", 16),
+            Ok(PgU16::from(255u16))
+        );
+        assert_eq!(
+            CheckedAdd::checked_add(&PgU16::max_value(), &PgU16::one()),
+            None
+        );
+        assert_eq!(
+            Saturating::saturating_add(PgU16::max_value(), PgU16::one()),
+            PgU16::max_value()
+        );
+        assert_eq!(PgU16::from(200u16).to_u8(), Some(200u8));
+        assert_eq!(PgU16::max_value().to_u128(), Some(u16::MAX as u128));
+        assert_eq!(PgU16::from_i64(-1), None);
+        assert_eq!(PgU16::from_u64(200), Some(PgU16::from(200u16)));
+        assert_eq!(
+            num_traits::clamp(PgU16::from(200u16), PgU16::zero(), PgU16::from(100u16)),
+            PgU16::from(100u16)
+        );
+    }
+
     #[test]
     fn try_from_bigdecimal() {
         let pg_u16 = PgU16::try_from(BigDecimal::from(126u16)).unwrap();
@@ -86,24 +251,84 @@ mod pg_u16_tests {
         let pg_u16 = PgU16::try_from(BigDecimal::from(-1));
         assert!(pg_u16.is_err());
         let err = pg_u16.unwrap_err();
-        assert_eq!(err, Error::InvalidValue(BigDecimal::from(-1)));
+        assert_eq!(
+            err,
+            Error::Negative {
+                type_name: "PgU16",
+                value: BigDecimal::from(-1)
+            }
+        );
 
         let fractional = BigDecimal::from(3) / BigDecimal::from(2);
         let pg_u16 = PgU16::try_from(fractional.clone());
-        assert_eq!(pg_u16.unwrap_err(), Error::Fractional(fractional));
+        assert_eq!(
+            pg_u16.unwrap_err(),
+            Error::Fractional {
+                type_name: "PgU16",
+                value: fractional
+            }
+        );
 
         let big_decimal = BigDecimal::from(BigInt::from(2).pow(128));
         let pg_u16 = PgU16::try_from(big_decimal.clone());
-        assert_eq!(pg_u16.unwrap_err(), Error::InvalidValue(big_decimal));
+        assert_eq!(
+            pg_u16.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU16",
+                value: big_decimal,
+                max: BigDecimal::from(u16::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_big_decimal() {
+        let pg_u16 = PgU16::from(u16::MAX);
+        assert_eq!(pg_u16.to_big_decimal(), BigDecimal::from(u16::MAX));
+        assert_eq!(BigDecimal::from(pg_u16), BigDecimal::from(u16::MAX));
+        assert_eq!(PgU16::try_from(pg_u16.to_big_decimal()).unwrap(), pg_u16);
+    }
+
+    #[test]
+    fn try_from_bigdecimal_out_of_range() {
+        let too_large = BigDecimal::from(u16::MAX) + BigDecimal::from(1);
+        let pg_u16 = PgU16::try_from(too_large.clone());
+        assert_eq!(
+            pg_u16.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU16",
+                value: too_large,
+                max: BigDecimal::from(u16::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_bigdecimal_with_scale() {
+        let scaled = BigDecimal::from_str("5.00").unwrap();
+        assert_eq!(PgU16::try_from(scaled).unwrap().to_uint(), 5u16);
+
+        let trailing_zeros = BigDecimal::from_str("120.000").unwrap();
+        assert_eq!(PgU16::try_from(trailing_zeros).unwrap().to_uint(), 120u16);
+
+        let exponent = BigDecimal::from_str("1E+2").unwrap();
+        assert_eq!(PgU16::try_from(exponent).unwrap().to_uint(), 100u16);
+
+        let negative_scale = BigDecimal::new(BigInt::from(12), -1);
+        assert_eq!(PgU16::try_from(negative_scale).unwrap().to_uint(), 120u16);
+
+        let max = BigDecimal::from(u16::MAX).with_scale(2);
+        assert_eq!(PgU16::try_from(max).unwrap().to_uint(), u16::MAX);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_option_conversion() {
         let somepguint = Some(PgU16::from(123u16));
         let someuint = somepguint.to_option_uint();
         assert_eq!(someuint, Some(123u16));
 
-        let pguint = PgU16::from(123);
+        let pguint = PgU16::from(123u16);
         let someuint = pguint.to_option_uint();
         assert_eq!(someuint, Some(123u16));
 
@@ -0,0 +1,714 @@
+use std::num::ParseIntError;
+
+use bigdecimal::num_bigint::{BigInt, BigUint};
+pub use ruint::aliases::U256;
+
+use super::*;
+use private::{Integer, Unsigned};
+
+/// PostgreSQL-compatible unsigned 256-bit integer
+///
+/// Unlike those of the primitives, the arithmetic operators of [`U256`] wrap around on overflow in
+/// debug builds as well, and so do the operators and `pow` of `PgU256`.
+pub type PgU256 = PgUint<U256>;
+
+impl private::Sealed for U256 {}
+
+// The inherent methods are called through `U256::` paths, as method call syntax would pick the
+// trait method over inherent methods which take `&self`, and recurse.
+impl Integer for U256 {
+    const NAME: &'static str = "PgU256";
+    const NUMERIC_PRECISION: u32 = 78;
+
+    const ZERO: Self = U256::ZERO;
+    const ONE: Self = U256::from_limbs([1, 0, 0, 0]);
+    const MIN: Self = U256::ZERO;
+    const MAX: Self = U256::MAX;
+    const BITS: u32 = 256;
+
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        U256::checked_add(self, rhs)
+    }
+    fn checked_sub(self, rhs: Self) -> Option<Self> {
+        U256::checked_sub(self, rhs)
+    }
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        U256::checked_mul(self, rhs)
+    }
+    fn checked_div(self, rhs: Self) -> Option<Self> {
+        U256::checked_div(self, rhs)
+    }
+    fn checked_rem(self, rhs: Self) -> Option<Self> {
+        U256::checked_rem(self, rhs)
+    }
+    fn wrapping_add(self, rhs: Self) -> Self {
+        U256::wrapping_add(self, rhs)
+    }
+    fn wrapping_sub(self, rhs: Self) -> Self {
+        U256::wrapping_sub(self, rhs)
+    }
+    fn wrapping_mul(self, rhs: Self) -> Self {
+        U256::wrapping_mul(self, rhs)
+    }
+    fn wrapping_div(self, rhs: Self) -> Self {
+        U256::wrapping_div(self, rhs)
+    }
+    fn wrapping_rem(self, rhs: Self) -> Self {
+        U256::wrapping_rem(self, rhs)
+    }
+    fn saturating_add(self, rhs: Self) -> Self {
+        U256::saturating_add(self, rhs)
+    }
+    fn saturating_sub(self, rhs: Self) -> Self {
+        U256::saturating_sub(self, rhs)
+    }
+    fn saturating_mul(self, rhs: Self) -> Self {
+        U256::saturating_mul(self, rhs)
+    }
+    fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        U256::overflowing_add(self, rhs)
+    }
+    fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        U256::overflowing_sub(self, rhs)
+    }
+    fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        U256::overflowing_mul(self, rhs)
+    }
+
+    // Unsigned division cannot overflow, which is why `U256` has no saturating or overflowing
+    // variants of it.
+    fn saturating_div(self, rhs: Self) -> Self {
+        U256::wrapping_div(self, rhs)
+    }
+    fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (U256::wrapping_div(self, rhs), false)
+    }
+    fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (U256::wrapping_rem(self, rhs), false)
+    }
+
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+        // `ParseIntError` cannot be constructed directly, so errors come from parsing a `u8` which
+        // fails the same way. `U256::from_str_radix` accepts input which the primitives reject,
+        // such as `_` separators.
+        let invalid_digit = || "?".parse::<u8>().map(U256::from);
+        if !(2..=36).contains(&radix) {
+            return invalid_digit();
+        }
+        let digits = src.strip_prefix('+').unwrap_or(src);
+        if digits.is_empty() {
+            // Fails with `Empty` for an empty string, and with `InvalidDigit` for a lone sign.
+            return src.parse::<u8>().map(U256::from);
+        }
+        digits.chars().try_fold(U256::ZERO, |value, digit| {
+            let Some(digit) = digit.to_digit(radix) else {
+                return invalid_digit();
+            };
+            U256::checked_mul(value, U256::from(radix))
+                .and_then(|value| U256::checked_add(value, U256::from(digit)))
+                .map_or_else(|| "256".parse::<u8>().map(U256::from), Ok)
+        })
+    }
+
+    fn try_from_big_int(value: &BigInt) -> Option<Self> {
+        value
+            .to_biguint()
+            .and_then(|value| U256::try_from_le_slice(&value.to_bytes_le()))
+    }
+
+    fn to_big_decimal(self) -> BigDecimal {
+        let digits = BigUint::from_bytes_le(&U256::to_le_bytes::<32>(&self));
+        BigDecimal::from(BigInt::from(digits))
+    }
+
+    fn fmt_lower_exp(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_exp(*self, f, 'e')
+    }
+
+    fn fmt_upper_exp(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_exp(*self, f, 'E')
+    }
+
+    /// Serializes the value as a decimal string, as 256-bit integers are not part of the serde
+    /// data model.
+    #[cfg(feature = "serde")]
+    fn serialize<S: serde::Serializer>(self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self)
+    }
+}
+
+impl Unsigned for U256 {
+    type NonZero = non_zero::NonZeroU256;
+
+    const NON_ZERO_NAME: &'static str = "PgNonZeroU256";
+    const BOUNDED_NAME: &'static str = "PgBounded<PgU256>";
+    const MAX_U128: u128 = u128::MAX;
+
+    fn pow(self, exp: u32) -> Self {
+        U256::pow(self, U256::from(exp))
+    }
+    fn checked_pow(self, exp: u32) -> Option<Self> {
+        U256::checked_pow(self, U256::from(exp))
+    }
+    fn isqrt(self) -> Self {
+        U256::root(self, 2)
+    }
+    fn ilog2(self) -> u32 {
+        U256::log2(self) as u32
+    }
+    fn ilog10(self) -> u32 {
+        U256::log10(self) as u32
+    }
+    fn count_ones(self) -> u32 {
+        U256::count_ones(&self) as u32
+    }
+    fn leading_zeros(self) -> u32 {
+        U256::leading_zeros(&self) as u32
+    }
+    fn trailing_zeros(self) -> u32 {
+        U256::trailing_zeros(&self) as u32
+    }
+    fn rotate_left(self, n: u32) -> Self {
+        U256::rotate_left(self, n as usize)
+    }
+    fn rotate_right(self, n: u32) -> Self {
+        U256::rotate_right(self, n as usize)
+    }
+    fn is_power_of_two(self) -> bool {
+        U256::is_power_of_two(self)
+    }
+    fn next_power_of_two(self) -> Self {
+        U256::next_power_of_two(self)
+    }
+    fn div_ceil(self, rhs: Self) -> Self {
+        U256::div_ceil(self, rhs)
+    }
+    fn abs_diff(self, other: Self) -> Self {
+        U256::abs_diff(self, other)
+    }
+
+    fn swap_bytes(self) -> Self {
+        U256::from_be_bytes(U256::to_le_bytes::<32>(&self))
+    }
+
+    fn midpoint(self, rhs: Self) -> Self {
+        (self & rhs) + ((self ^ rhs) >> 1u32)
+    }
+
+    fn to_non_zero(self) -> Option<Self::NonZero> {
+        non_zero::NonZeroU256::new(self)
+    }
+
+    fn from_non_zero(value: Self::NonZero) -> Self {
+        value.get()
+    }
+
+    fn to_u128(self) -> Option<u128> {
+        u128::try_from(self).ok()
+    }
+}
+
+impl UIntType for U256 {}
+
+mod non_zero {
+    use super::U256;
+
+    /// A `U256` which is known not to be zero, in place of the `std::num::NonZero*` types, which
+    /// do not cover it. Public in a private module, so it cannot be named outside of this crate.
+    #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct NonZeroU256(U256);
+
+    impl NonZeroU256 {
+        pub(super) fn new(value: U256) -> Option<Self> {
+            if value.is_zero() {
+                None
+            } else {
+                Some(Self(value))
+            }
+        }
+
+        pub(super) const fn get(self) -> U256 {
+            self.0
+        }
+    }
+
+    impl std::fmt::Display for NonZeroU256 {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Display::fmt(&self.0, f)
+        }
+    }
+
+    impl std::fmt::Debug for NonZeroU256 {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            std::fmt::Debug::fmt(&self.0, f)
+        }
+    }
+}
+
+/// Formats `value` in scientific notation, like the `LowerExp` and `UpperExp` implementations of
+/// the primitives: trailing zeros are dropped, and a precision rounds half to even.
+fn fmt_exp(value: U256, f: &mut std::fmt::Formatter<'_>, e: char) -> std::fmt::Result {
+    let digits = value.to_string();
+    let mut exponent = digits.len() - 1;
+    let mantissa = match f.precision() {
+        Some(precision) if precision < exponent => {
+            let divisor = U256::pow(U256::from(10u8), U256::from(exponent - precision));
+            let half = divisor / U256::from(2u8);
+            let (mut kept, dropped) = (value / divisor, value % divisor);
+            if dropped > half || (dropped == half && kept.bit(0)) {
+                kept += U256::from(1u8);
+            }
+            let mut mantissa = kept.to_string();
+            // Rounding up `9.99` gives `10.0`, which is written as `1.00` with a larger exponent.
+            if mantissa.len() > precision + 1 {
+                mantissa.pop();
+                exponent += 1;
+            }
+            mantissa
+        }
+        Some(precision) => format!("{digits:0<width$}", width = precision + 1),
+        None => match digits.trim_end_matches('0') {
+            "" => "0".to_owned(),
+            trimmed => trimmed.to_owned(),
+        },
+    };
+    let (first, rest) = mantissa.split_at(1);
+    let formatted = if rest.is_empty() {
+        format!("{first}{e}{exponent}")
+    } else {
+        format!("{first}.{rest}{e}{exponent}")
+    };
+    f.pad_integral(true, "", &formatted)
+}
+
+#[cfg(test)]
+mod pg_u256_tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_to_u256() {
+        let pg_u256 = PgU256::from(U256::from(12678671u64));
+        assert_eq!(pg_u256.to_uint(), U256::from(12678671u64));
+        let pg_u256 = PgU256::default();
+        assert_eq!(pg_u256.to_uint(), U256::ZERO);
+        let pg_u256 = PgU256::from(U256::MAX);
+        assert_eq!(pg_u256.to_uint(), U256::MAX);
+    }
+
+    #[test]
+    fn try_from_bigdecimal() {
+        let pg_u256 = PgU256::try_from(BigDecimal::from(u128::MAX)).unwrap();
+        assert_eq!(pg_u256.to_uint(), U256::from(u128::MAX));
+
+        let pg_u256 = PgU256::try_from(BigDecimal::from(0)).unwrap();
+        assert_eq!(pg_u256.to_uint(), U256::ZERO);
+
+        let max = BigDecimal::from(BigInt::from(2).pow(256) - 1);
+        let pg_u256 = PgU256::try_from(max.clone()).unwrap();
+        assert_eq!(pg_u256.to_uint(), U256::MAX);
+        assert_eq!(pg_u256.to_big_decimal(), max);
+
+        let pg_u256 = PgU256::try_from(BigDecimal::from(-1));
+        assert_eq!(
+            pg_u256.unwrap_err(),
+            Error::Negative {
+                type_name: "PgU256",
+                value: BigDecimal::from(-1)
+            }
+        );
+
+        let fractional = BigDecimal::from(3) / BigDecimal::from(2);
+        let pg_u256 = PgU256::try_from(fractional.clone());
+        assert_eq!(
+            pg_u256.unwrap_err(),
+            Error::Fractional {
+                type_name: "PgU256",
+                value: fractional
+            }
+        );
+
+        let too_large = BigDecimal::from(BigInt::from(2).pow(256));
+        let pg_u256 = PgU256::try_from(too_large.clone());
+        assert_eq!(
+            pg_u256.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU256",
+                value: too_large,
+                max
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_bigdecimal_with_scale() {
+        let scaled = BigDecimal::from_str("5.00").unwrap();
+        assert_eq!(PgU256::try_from(scaled).unwrap().to_uint(), U256::from(5u8));
+
+        let exponent = BigDecimal::from_str("1E+70").unwrap();
+        assert_eq!(
+            PgU256::try_from(exponent).unwrap().to_uint(),
+            U256::from(10u8).pow(U256::from(70u8))
+        );
+    }
+
+    #[test]
+    fn try_from_bigdecimal_huge_exponent() {
+        let huge = BigDecimal::from_str("1e100000000").unwrap();
+        assert!(matches!(
+            PgU256::try_from(huge),
+            Err(Error::TooLarge {
+                type_name: "PgU256",
+                ..
+            })
+        ));
+        let negative = BigDecimal::from_str("-1e100000000").unwrap();
+        assert!(matches!(
+            PgU256::try_from(negative),
+            Err(Error::Negative { .. })
+        ));
+        let tiny = BigDecimal::from_str("1e-100000000").unwrap();
+        assert!(matches!(
+            PgU256::try_from(tiny),
+            Err(Error::Fractional { .. })
+        ));
+        let zero = BigDecimal::from_str("0e-100000000").unwrap();
+        assert_eq!(PgU256::try_from(zero).unwrap(), PgU256::ZERO);
+        let too_large = BigDecimal::from_str("1e78").unwrap();
+        assert!(PgU256::try_from(too_large).is_err());
+    }
+
+    #[test]
+    fn test_from_str() {
+        let max = "115792089237316195423570985008687907853269984665640564039457584007913129639935";
+        assert_eq!(PgU256::from_str(max).unwrap().to_uint(), U256::MAX);
+        assert_eq!(PgU256::from_str(max).unwrap().to_string(), max);
+        assert!(PgU256::from_str("").is_err());
+        assert!(PgU256::from_str("-1").is_err());
+        assert!(PgU256::from_str("0x10").is_err());
+
+        let too_large =
+            "115792089237316195423570985008687907853269984665640564039457584007913129639936";
+        assert_eq!(
+            PgU256::from_str(too_large).unwrap_err(),
+            Error::Parse {
+                type_name: "PgU256",
+                input: too_large.to_owned(),
+                kind: IntErrorKind::PosOverflow
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_radix() {
+        // The primitives panic on these
+        for radix in [0, 1, 37, u32::MAX] {
+            assert_eq!(
+                PgU256::from_str_radix("1", radix).unwrap_err(),
+                Error::Parse {
+                    type_name: "PgU256",
+                    input: "1".to_owned(),
+                    kind: IntErrorKind::InvalidDigit
+                }
+            );
+            assert!(PgU256::from_str_radix("", radix).is_err());
+        }
+        assert_eq!(
+            PgU256::from_str_radix("z", 36).unwrap().to_uint(),
+            U256::from(35u8)
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        assert_eq!(
+            PgU256::parse_lenient(" 0xFF_FF ").unwrap().to_uint(),
+            U256::from(0xFFFFu32)
+        );
+        assert_eq!(
+            PgU256::parse_lenient("1e70").unwrap().to_uint(),
+            U256::from(10u8).pow(U256::from(70u8))
+        );
+        assert_eq!(
+            PgU256::from_str_radix("ff", 16).unwrap().to_uint(),
+            U256::from(255u8)
+        );
+        assert!(PgU256::parse_lenient("-0x1").is_err());
+        assert!(PgU256::parse_lenient("1e80").is_err());
+    }
+
+    #[test]
+    fn test_consts() {
+        const THRESHOLD: PgU256 = PgU256::new(U256::ZERO);
+        assert_eq!(THRESHOLD, PgU256::ZERO);
+        assert_eq!(PgU256::MAX.get(), U256::MAX);
+        assert_eq!(PgU256::ONE.to_uint(), U256::from(1u8));
+        assert_eq!(
+            PgU256::MAX.to_string().len() as u32,
+            PgU256::NUMERIC_PRECISION
+        );
+    }
+
+    #[test]
+    fn test_pg_unsigned() {
+        fn next_id<Id: PgUnsigned>(last: Id) -> Option<Id> {
+            last.checked_add(Id::ONE)
+        }
+
+        assert_eq!(
+            next_id(PgU256::from(U256::from(41u8))),
+            Some(PgU256::from(U256::from(42u8)))
+        );
+        assert_eq!(next_id(PgU256::MAX), None);
+        assert_eq!(<PgU256 as PgUnsigned>::BITS, 256);
+        assert_eq!(<PgU256 as PgUnsigned>::new(U256::ZERO), PgU256::ZERO);
+        assert_eq!(PgU256::ONE.checked_div(PgU256::ZERO), None);
+        assert_eq!(PgU256::ONE.checked_rem(PgU256::ZERO), None);
+        assert_eq!(
+            PgU256::MAX.checked_rem(PgU256::from(U256::from(10u8))),
+            Some(PgU256::from(U256::from(5u8)))
+        );
+        let _ =
+            sqlx::query("SELECT * FROM balances WHERE amount = ANY($1)").bind(vec![PgU256::ONE]);
+    }
+
+    #[test]
+    fn test_fmt() {
+        let pg_u256 = PgU256::from(U256::from(255u8));
+        assert_eq!(format!("{:>5}", pg_u256), "  255");
+        assert_eq!(format!("{:#x}", pg_u256), "0xff");
+        assert_eq!(format!("{:X}", pg_u256), "FF");
+        assert_eq!(format!("{:o}", pg_u256), "377");
+        assert_eq!(format!("{:b}", pg_u256), "11111111");
+        assert_eq!(format!("{:?}", pg_u256), "255");
+        assert_eq!(format!("{:e}", pg_u256), "2.55e2");
+        assert_eq!(
+            format!("{:E}", PgU256::MAX),
+            "1.15792089237316195423570985008687907853269984665640564039457584007913129639935E77"
+        );
+        assert_eq!(format!("{:.3e}", PgU256::MAX), "1.158e77");
+    }
+
+    #[test]
+    fn test_fmt_exp_matches_primitives() {
+        let values = [
+            0u128, 1, 5, 9, 10, 25, 35, 95, 99, 100, 125, 135, 250, 251, 999, 1_000_500,
+        ];
+        for value in values.into_iter().chain([u64::MAX as u128, u128::MAX]) {
+            let pg_u256 = PgU256::from(U256::from(value));
+            assert_eq!(format!("{:e}", pg_u256), format!("{:e}", value));
+            assert_eq!(format!("{:E}", pg_u256), format!("{:E}", value));
+            assert_eq!(format!("{:>12e}", pg_u256), format!("{:>12e}", value));
+            assert_eq!(format!("{:+012e}", pg_u256), format!("{:+012e}", value));
+            for precision in 0..5 {
+                assert_eq!(
+                    format!("{:.*e}", precision, pg_u256),
+                    format!("{:.*e}", precision, value),
+                    "{value} with precision {precision}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_ops() {
+        let a = PgU256::from(U256::from(12u8));
+        let b = PgU256::from(U256::from(5u8));
+        assert_eq!(a + b, U256::from(17u8));
+        assert_eq!(a - b, U256::from(7u8));
+        assert_eq!(a * b, U256::from(60u8));
+        assert_eq!(a / b, U256::from(2u8));
+        assert_eq!(a % b, U256::from(2u8));
+        assert_eq!(a & b, U256::from(4u8));
+        assert_eq!(a | b, U256::from(13u8));
+        assert_eq!(a ^ b, U256::from(9u8));
+        assert_eq!(a << 2, U256::from(48u8));
+        assert_eq!(a >> 2, U256::from(3u8));
+        assert_eq!(!PgU256::ZERO, PgU256::MAX);
+
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(a + U256::from(1u8), U256::from(13u8));
+        assert_eq!(U256::from(1u8) + a, U256::from(13u8));
+        let mut c = a;
+        c += b;
+        c -= U256::from(1u8);
+        c <<= 1;
+        assert_eq!(c, U256::from(32u8));
+        assert!(U256::from(12u8) == a);
+        assert!(U256::from(13u8) > a);
+
+        assert_eq!([a, b].iter().sum::<PgU256>(), U256::from(17u8));
+        assert_eq!([a, b].into_iter().product::<PgU256>(), U256::from(60u8));
+        assert_eq!(PgU256::checked_sum([PgU256::MAX, PgU256::ONE]), None);
+    }
+
+    #[test]
+    fn test_overflow_wraps() {
+        assert_eq!(PgU256::MAX + PgU256::ONE, PgU256::ZERO);
+        assert_eq!(PgU256::ZERO - PgU256::ONE, PgU256::MAX);
+    }
+
+    #[test]
+    fn test_arithmetic_methods() {
+        let two = PgU256::from(U256::from(2u8));
+        assert_eq!(PgU256::MAX.checked_add(PgU256::ONE), None);
+        assert_eq!(PgU256::ZERO.checked_sub(PgU256::ONE), None);
+        assert_eq!(PgU256::MAX.checked_mul(two), None);
+        assert_eq!(PgU256::MAX.wrapping_add(two), PgU256::ONE);
+        assert_eq!(PgU256::ZERO.wrapping_sub(PgU256::ONE), PgU256::MAX);
+        assert_eq!(PgU256::MAX.wrapping_div(two), PgU256::MAX >> 1);
+        assert_eq!(PgU256::MAX.wrapping_rem(two), PgU256::ONE);
+        assert_eq!(PgU256::MAX.saturating_add(PgU256::ONE), PgU256::MAX);
+        assert_eq!(PgU256::ZERO.saturating_sub(PgU256::ONE), PgU256::ZERO);
+        assert_eq!(PgU256::MAX.saturating_mul(two), PgU256::MAX);
+        assert_eq!(PgU256::MAX.saturating_div(two), PgU256::MAX >> 1);
+        assert_eq!(
+            PgU256::MAX.overflowing_add(PgU256::ONE),
+            (PgU256::ZERO, true)
+        );
+        assert_eq!(
+            PgU256::ZERO.overflowing_sub(PgU256::ONE),
+            (PgU256::MAX, true)
+        );
+        assert_eq!(
+            PgU256::MAX.overflowing_mul(PgU256::ONE),
+            (PgU256::MAX, false)
+        );
+        assert_eq!(PgU256::MAX.overflowing_div(two), (PgU256::MAX >> 1, false));
+        assert_eq!(PgU256::MAX.overflowing_rem(two), (PgU256::ONE, false));
+    }
+
+    #[test]
+    fn test_unsigned_methods() {
+        let two = PgU256::from(U256::from(2u8));
+        assert_eq!(two.pow(255), PgU256::ONE << 255);
+        assert_eq!(two.checked_pow(256), None);
+        assert_eq!(PgU256::MAX.isqrt(), PgU256::from(U256::from(u128::MAX)));
+        assert_eq!(PgU256::MAX.ilog2(), 255);
+        assert_eq!(PgU256::MAX.ilog10(), 77);
+        assert_eq!(PgU256::MAX.count_ones(), 256);
+        assert_eq!(PgU256::ONE.leading_zeros(), 255);
+        assert_eq!((PgU256::ONE << 100).trailing_zeros(), 100);
+        assert_eq!(PgU256::ONE.rotate_right(1), PgU256::ONE << 255);
+        assert_eq!((PgU256::ONE << 255).rotate_left(1), PgU256::ONE);
+        assert_eq!(PgU256::ONE.swap_bytes(), PgU256::ONE << 248);
+        assert!((PgU256::ONE << 200).is_power_of_two());
+        assert_eq!(
+            PgU256::from(U256::from(5u8)).next_power_of_two(),
+            U256::from(8u8)
+        );
+        assert_eq!(PgU256::from(U256::from(7u8)).div_ceil(two), U256::from(4u8));
+        assert_eq!(PgU256::ONE.abs_diff(PgU256::MAX), PgU256::MAX - PgU256::ONE);
+        assert_eq!(PgU256::MAX.midpoint(PgU256::MAX), PgU256::MAX);
+        assert_eq!(PgU256::ONE.midpoint(two), PgU256::ONE);
+    }
+
+    #[test]
+    fn test_cross_width() {
+        assert_eq!(PgU256::from(PgU64::from(u64::MAX)), U256::from(u64::MAX));
+        assert_eq!(PgU256::from(u128::MAX), U256::from(u128::MAX));
+        let widened: U256 = PgU8::from(7u8).into();
+        assert_eq!(widened, U256::from(7u8));
+        assert_eq!(
+            PgU8::try_from(PgU256::from(U256::from(255u8))).unwrap(),
+            255u8
+        );
+        assert_eq!(
+            PgU128::try_from(PgU256::MAX).unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU128",
+                value: PgU256::MAX.to_big_decimal(),
+                max: BigDecimal::from(u128::MAX)
+            }
+        );
+        assert_eq!(u64::try_from(PgU256::from(U256::from(42u8))).unwrap(), 42);
+        assert_eq!(PgU256::try_from(42i64).unwrap(), U256::from(42u8));
+        assert!(PgU256::try_from(-1i64).is_err());
+
+        assert_eq!(PgU256::from(U256::from(42u8)), PgU64::from(42u64));
+        assert_eq!(PgU32::from(42u32), PgU256::from(U256::from(42u8)));
+        assert!(PgU256::MAX > PgU128::MAX);
+        assert!(PgU8::MAX < PgU256::from(U256::from(256u16)));
+    }
+
+    #[test]
+    fn test_uint_type() {
+        fn double<T: UIntType>(value: PgUint<T>) -> PgUint<T> {
+            value + value
+        }
+
+        assert_eq!(double(PgU256::from(U256::from(21u8))), U256::from(42u8));
+
+        let non_zero = PgNonZeroU256::new(U256::from(7u8)).unwrap();
+        assert_eq!(non_zero.to_pg_uint(), PgU256::from(U256::from(7u8)));
+        assert_eq!(format!("{:?}", non_zero), "7");
+        assert_eq!(
+            PgNonZeroU256::try_from(PgU256::ZERO).unwrap_err(),
+            Error::Zero {
+                type_name: "PgNonZeroU256",
+                value: BigDecimal::from(0)
+            }
+        );
+
+        type Limit = PgBounded<U256, 1, { u128::MAX }>;
+        assert!(Limit::new(PgU256::from(u128::MAX)).is_ok());
+        assert!(Limit::new(PgU256::ZERO).is_err());
+        assert_eq!(
+            Limit::new(PgU256::MAX).unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgBounded<PgU256>",
+                value: PgU256::MAX.to_big_decimal(),
+                max: BigDecimal::from(u128::MAX)
+            }
+        );
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{Bounded, CheckedAdd, FromPrimitive, Num, One, ToPrimitive, Zero};
+
+        assert_eq!(PgU256::max_value(), PgU256::MAX);
+        assert!(PgU256::zero().is_zero());
+        assert_eq!(
+            CheckedAdd::checked_add(&PgU256::max_value(), &PgU256::one()),
+            None
+        );
+        assert_eq!(
+            <PgU256 as Num>::from_str_radix("ff", 16),
+            Ok(PgU256::from(U256::from(255u8)))
+        );
+        assert_eq!(PgU256::from(U256::from(200u8)).to_u8(), Some(200u8));
+        assert_eq!(PgU256::MAX.to_u128(), None);
+        assert_eq!(PgU256::from_u64(7), Some(PgU256::from(U256::from(7u8))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use serde_test::{assert_de_tokens, assert_tokens, Token};
+
+        let pg_u256 = PgU256::from(U256::MAX);
+        assert_tokens(
+            &pg_u256,
+            &[Token::Str(
+                "115792089237316195423570985008687907853269984665640564039457584007913129639935",
+            )],
+        );
+        assert_de_tokens(&PgU256::from(U256::from(42u8)), &[Token::U64(42)]);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_option_conversion() {
+        let somepguint = Some(PgU256::from(U256::from(123u8)));
+        let someuint = somepguint.to_option_uint();
+        assert_eq!(someuint, Some(U256::from(123u8)));
+
+        let pguint: Option<PgU256> = None;
+        let someuint = pguint.to_option_uint();
+        assert_eq!(someuint, None::<U256>);
+    }
+}
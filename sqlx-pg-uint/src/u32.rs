@@ -1,36 +1,12 @@
 use super::*;
-use sqlx_pg_uint_macros::UIntWrapper;
 
-use crate::UIntType;
-
-impl UIntType for PgU32 {
-    type Uint = u32;
-}
-
-impl private::Sealed for PgU32 {}
-#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, UIntWrapper, sqlx::FromRow)]
 /// PostgreSQL-compatible unsigned 32-bit integer
-pub struct PgU32 {
-    inner: BigDecimal,
-}
-
-impl From<PgU32> for u32 {
-    fn from(value: PgU32) -> Self {
-        value.inner.to_string().parse().unwrap()
-    }
-}
-
-impl From<u32> for PgU32 {
-    fn from(value: u32) -> Self {
-        Self {
-            inner: BigDecimal::from(value),
-        }
-    }
-}
+pub type PgU32 = PgUint<u32>;
 
 #[cfg(test)]
 mod pg_u32_tests {
     use bigdecimal::num_bigint::BigInt;
+    use std::str::FromStr;
 
     use super::*;
 
@@ -38,7 +14,7 @@ mod pg_u32_tests {
     fn test_to_u32() {
         let pg_u32 = PgU32::from(121u32);
         assert_eq!(pg_u32.to_uint(), 121u32);
-        let pg_u32 = PgU32::from(0);
+        let pg_u32 = PgU32::from(0u32);
         assert_eq!(pg_u32.to_uint(), 0u32);
         let pg_u32 = PgU32::from(u32::MAX);
         assert_eq!(pg_u32.to_uint(), u32::MAX);
@@ -71,6 +47,196 @@ mod pg_u32_tests {
         let _ = pg_u32 - pg_u322;
     }
 
+    #[test]
+    fn test_checked_ops() {
+        let max = PgU32::from(u32::MAX);
+        let one = PgU32::from(1u32);
+        let zero = PgU32::from(0u32);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(zero.checked_sub(one), None);
+        assert_eq!(max.checked_mul(PgU32::from(2u32)), None);
+        assert_eq!(one.checked_div(zero), None);
+        assert_eq!(one.checked_rem(zero), None);
+        assert_eq!(one.checked_add(one), Some(PgU32::from(2u32)));
+        assert_eq!(max.checked_div(max), Some(one));
+        assert_eq!(max.checked_rem(max), Some(zero));
+    }
+
+    #[test]
+    fn test_wrapping_ops() {
+        let max = PgU32::from(u32::MAX);
+        let one = PgU32::from(1u32);
+        let zero = PgU32::from(0u32);
+        assert_eq!(max.wrapping_add(one), zero);
+        assert_eq!(zero.wrapping_sub(one), max);
+        assert_eq!(
+            max.wrapping_mul(PgU32::from(2u32)),
+            PgU32::from(u32::MAX - 1)
+        );
+        assert_eq!(max.wrapping_div(one), max);
+        assert_eq!(max.wrapping_rem(PgU32::from(2u32)), one);
+    }
+
+    #[test]
+    fn test_saturating_ops() {
+        let max = PgU32::from(u32::MAX);
+        let one = PgU32::from(1u32);
+        let zero = PgU32::from(0u32);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(zero.saturating_sub(one), zero);
+        assert_eq!(max.saturating_mul(PgU32::from(2u32)), max);
+        assert_eq!(max.saturating_div(one), max);
+    }
+
+    #[test]
+    fn test_overflowing_ops() {
+        let max = PgU32::from(u32::MAX);
+        let one = PgU32::from(1u32);
+        let zero = PgU32::from(0u32);
+        assert_eq!(max.overflowing_add(one), (zero, true));
+        assert_eq!(zero.overflowing_sub(one), (max, true));
+        assert_eq!(one.overflowing_mul(max), (max, false));
+        assert_eq!(max.overflowing_div(one), (max, false));
+        assert_eq!(max.overflowing_rem(one), (zero, false));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_ref_ops() {
+        let a = PgU32::from(12u32);
+        let b = PgU32::from(5u32);
+        assert_eq!(&a + &b, PgU32::from(17u32));
+        assert_eq!(&a - b, PgU32::from(7u32));
+        assert_eq!(a * &b, PgU32::from(60u32));
+        assert_eq!(&a / &b, PgU32::from(2u32));
+        assert_eq!(&a % &b, PgU32::from(2u32));
+
+        let mut c = a;
+        c += &b;
+        c %= b;
+        c %= &a;
+        assert_eq!(c, PgU32::from(2u32));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_bitwise_ops() {
+        let a = PgU32::from(0b1100u32);
+        let b = PgU32::from(0b1010u32);
+        assert_eq!(a & b, PgU32::from(0b1000u32));
+        assert_eq!(&a | &b, PgU32::from(0b1110u32));
+        assert_eq!(a ^ &b, PgU32::from(0b0110u32));
+        assert_eq!(!PgU32::from(0u32), PgU32::from(u32::MAX));
+        assert_eq!(!&a, PgU32::from(!0b1100u32));
+        assert_eq!(a << 2, PgU32::from(0b110000u32));
+        assert_eq!(&a >> 2, PgU32::from(0b11u32));
+        assert_eq!(a & 0b0100, PgU32::from(0b0100u32));
+
+        let mut c = a;
+        c |= b;
+        c &= &a;
+        c ^= 0b0001;
+        c <<= 1;
+        c >>= 2;
+        assert_eq!(c, PgU32::from(0b0110u32));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values = vec![PgU32::from(2u32), PgU32::from(3u32), PgU32::from(4u32)];
+        assert_eq!(values.iter().sum::<PgU32>(), PgU32::from(9u32));
+        assert_eq!(values.iter().product::<PgU32>(), PgU32::from(24u32));
+        assert_eq!(values.clone().into_iter().sum::<PgU32>(), PgU32::from(9u32));
+        assert_eq!(
+            values.clone().into_iter().product::<PgU32>(),
+            PgU32::from(24u32)
+        );
+        assert_eq!(
+            Vec::<PgU32>::new().into_iter().sum::<PgU32>(),
+            PgU32::from(0u32)
+        );
+        assert_eq!(
+            Vec::<PgU32>::new().into_iter().product::<PgU32>(),
+            PgU32::from(1u32)
+        );
+
+        assert_eq!(PgU32::checked_sum(&values), Some(PgU32::from(9u32)));
+        assert_eq!(PgU32::checked_product(values), Some(PgU32::from(24u32)));
+
+        let overflowing = [PgU32::from(u32::MAX), PgU32::from(2u32)];
+        assert_eq!(PgU32::checked_sum(overflowing), None);
+        assert_eq!(PgU32::checked_product(overflowing.iter()), None);
+    }
+
+    #[test]
+    fn test_primitive_ops() {
+        let pg_u32 = PgU32::from(12u32);
+        assert_eq!(pg_u32 + 3, PgU32::from(15u32));
+        assert_eq!(pg_u32 - 3, PgU32::from(9u32));
+        assert_eq!(pg_u32 * 3, PgU32::from(36u32));
+        assert_eq!(pg_u32 / 5, PgU32::from(2u32));
+        assert_eq!(pg_u32 % 5, PgU32::from(2u32));
+        assert_eq!(3 + pg_u32, PgU32::from(15u32));
+        assert_eq!(20 - pg_u32, PgU32::from(8u32));
+        assert_eq!(3 * pg_u32, PgU32::from(36u32));
+        assert_eq!(36 / pg_u32, PgU32::from(3u32));
+        assert_eq!(17 % pg_u32, PgU32::from(5u32));
+
+        let mut pg_u32 = PgU32::from(12u32);
+        pg_u32 += 8;
+        pg_u32 -= 4;
+        pg_u32 *= 3;
+        pg_u32 /= 2;
+        pg_u32 %= 7;
+        assert_eq!(pg_u32, PgU32::from(3u32));
+    }
+
+    #[test]
+    fn test_primitive_cmp() {
+        let pg_u32 = PgU32::from(12u32);
+        assert_eq!(pg_u32, 12u32);
+        assert_eq!(12u32, pg_u32);
+        assert_ne!(pg_u32, 13u32);
+        assert!(pg_u32 < 13u32);
+        assert!(pg_u32 > 11u32);
+        assert!(11u32 < pg_u32);
+        assert!(u32::MAX > pg_u32);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{
+            Bounded, CheckedAdd, FromPrimitive, Num, One, Saturating, ToPrimitive, Zero,
+        };
+
+        assert_eq!(PgU32::zero(), PgU32::from(0u32));
+        assert!(PgU32::zero().is_zero());
+        assert_eq!(PgU32::one(), PgU32::from(1u32));
+        assert_eq!(PgU32::min_value(), PgU32::from(u32::MIN));
+        assert_eq!(PgU32::max_value(), PgU32::from(u32::MAX));
+        assert_eq!(
+            <PgU32 as Num>::from_str_radix("ff", 16),
+            Ok(PgU32::from(255u32))
+        );
+        assert_eq!(
+            CheckedAdd::checked_add(&PgU32::max_value(), &PgU32::one()),
+            None
+        );
+        assert_eq!(
+            Saturating::saturating_add(PgU32::max_value(), PgU32::one()),
+            PgU32::max_value()
+        );
+        assert_eq!(PgU32::from(200u32).to_u8(), Some(200u8));
+        assert_eq!(PgU32::max_value().to_u128(), Some(u32::MAX as u128));
+        assert_eq!(PgU32::from_i64(-1), None);
+        assert_eq!(PgU32::from_u64(200), Some(PgU32::from(200u32)));
+        assert_eq!(
+            num_traits::clamp(PgU32::from(200u32), PgU32::zero(), PgU32::from(100u32)),
+            PgU32::from(100u32)
+        );
+    }
+
     #[test]
     fn try_from_bigdecimal() {
         let pg_u32 = PgU32::try_from(BigDecimal::from(126u32)).unwrap();
@@ -85,24 +251,84 @@ mod pg_u32_tests {
         let pg_u32 = PgU32::try_from(BigDecimal::from(-1));
         assert!(pg_u32.is_err());
         let err = pg_u32.unwrap_err();
-        assert_eq!(err, Error::InvalidValue(BigDecimal::from(-1)));
+        assert_eq!(
+            err,
+            Error::Negative {
+                type_name: "PgU32",
+                value: BigDecimal::from(-1)
+            }
+        );
 
         let fractional = BigDecimal::from(3) / BigDecimal::from(2);
         let pg_u32 = PgU32::try_from(fractional.clone());
-        assert_eq!(pg_u32.unwrap_err(), Error::Fractional(fractional));
+        assert_eq!(
+            pg_u32.unwrap_err(),
+            Error::Fractional {
+                type_name: "PgU32",
+                value: fractional
+            }
+        );
 
         let big_decimal = BigDecimal::from(BigInt::from(2).pow(128));
         let pg_u32 = PgU32::try_from(big_decimal.clone());
-        assert_eq!(pg_u32.unwrap_err(), Error::InvalidValue(big_decimal));
+        assert_eq!(
+            pg_u32.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU32",
+                value: big_decimal,
+                max: BigDecimal::from(u32::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_big_decimal() {
+        let pg_u32 = PgU32::from(u32::MAX);
+        assert_eq!(pg_u32.to_big_decimal(), BigDecimal::from(u32::MAX));
+        assert_eq!(BigDecimal::from(pg_u32), BigDecimal::from(u32::MAX));
+        assert_eq!(PgU32::try_from(pg_u32.to_big_decimal()).unwrap(), pg_u32);
+    }
+
+    #[test]
+    fn try_from_bigdecimal_out_of_range() {
+        let too_large = BigDecimal::from(u32::MAX) + BigDecimal::from(1);
+        let pg_u32 = PgU32::try_from(too_large.clone());
+        assert_eq!(
+            pg_u32.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU32",
+                value: too_large,
+                max: BigDecimal::from(u32::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_bigdecimal_with_scale() {
+        let scaled = BigDecimal::from_str("5.00").unwrap();
+        assert_eq!(PgU32::try_from(scaled).unwrap().to_uint(), 5u32);
+
+        let trailing_zeros = BigDecimal::from_str("120.000").unwrap();
+        assert_eq!(PgU32::try_from(trailing_zeros).unwrap().to_uint(), 120u32);
+
+        let exponent = BigDecimal::from_str("1E+2").unwrap();
+        assert_eq!(PgU32::try_from(exponent).unwrap().to_uint(), 100u32);
+
+        let negative_scale = BigDecimal::new(BigInt::from(12), -1);
+        assert_eq!(PgU32::try_from(negative_scale).unwrap().to_uint(), 120u32);
+
+        let max = BigDecimal::from(u32::MAX).with_scale(2);
+        assert_eq!(PgU32::try_from(max).unwrap().to_uint(), u32::MAX);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_option_conversion() {
         let somepguint = Some(PgU32::from(123u32));
         let someuint = somepguint.to_option_uint();
         assert_eq!(someuint, Some(123u32));
 
-        let pguint = PgU32::from(123);
+        let pguint = PgU32::from(123u32);
         let someuint = pguint.to_option_uint();
         assert_eq!(someuint, Some(123u32));
 
@@ -1,36 +1,12 @@
 use super::*;
-use sqlx_pg_uint_macros::UIntWrapper;
 
-use crate::UIntType;
-
-impl UIntType for PgU64 {
-    type Uint = u64;
-}
-impl private::Sealed for PgU64 {}
-
-#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, UIntWrapper, sqlx::FromRow)]
 /// PostgreSQL-compatible unsigned 64-bit integer
-pub struct PgU64 {
-    inner: BigDecimal,
-}
-
-impl From<PgU64> for u64 {
-    fn from(value: PgU64) -> Self {
-        value.inner.to_string().parse().unwrap()
-    }
-}
-
-impl From<u64> for PgU64 {
-    fn from(value: u64) -> Self {
-        Self {
-            inner: BigDecimal::from(value),
-        }
-    }
-}
+pub type PgU64 = PgUint<u64>;
 
 #[cfg(test)]
 mod pg_u64_tests {
     use bigdecimal::num_bigint::BigInt;
+    use std::str::FromStr;
 
     use super::*;
 
@@ -38,7 +14,7 @@ mod pg_u64_tests {
     fn test_to_u64() {
         let pg_u64 = PgU64::from(121u64);
         assert_eq!(pg_u64.to_uint(), 121u64);
-        let pg_u64 = PgU64::from(0);
+        let pg_u64 = PgU64::from(0u64);
         assert_eq!(pg_u64.to_uint(), 0u64);
         let pg_u64 = PgU64::from(u64::MAX);
         assert_eq!(pg_u64.to_uint(), u64::MAX);
@@ -71,6 +47,196 @@ mod pg_u64_tests {
         let _ = pg_u64 - pg_u642;
     }
 
+    #[test]
+    fn test_checked_ops() {
+        let max = PgU64::from(u64::MAX);
+        let one = PgU64::from(1u64);
+        let zero = PgU64::from(0u64);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(zero.checked_sub(one), None);
+        assert_eq!(max.checked_mul(PgU64::from(2u64)), None);
+        assert_eq!(one.checked_div(zero), None);
+        assert_eq!(one.checked_rem(zero), None);
+        assert_eq!(one.checked_add(one), Some(PgU64::from(2u64)));
+        assert_eq!(max.checked_div(max), Some(one));
+        assert_eq!(max.checked_rem(max), Some(zero));
+    }
+
+    #[test]
+    fn test_wrapping_ops() {
+        let max = PgU64::from(u64::MAX);
+        let one = PgU64::from(1u64);
+        let zero = PgU64::from(0u64);
+        assert_eq!(max.wrapping_add(one), zero);
+        assert_eq!(zero.wrapping_sub(one), max);
+        assert_eq!(
+            max.wrapping_mul(PgU64::from(2u64)),
+            PgU64::from(u64::MAX - 1)
+        );
+        assert_eq!(max.wrapping_div(one), max);
+        assert_eq!(max.wrapping_rem(PgU64::from(2u64)), one);
+    }
+
+    #[test]
+    fn test_saturating_ops() {
+        let max = PgU64::from(u64::MAX);
+        let one = PgU64::from(1u64);
+        let zero = PgU64::from(0u64);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(zero.saturating_sub(one), zero);
+        assert_eq!(max.saturating_mul(PgU64::from(2u64)), max);
+        assert_eq!(max.saturating_div(one), max);
+    }
+
+    #[test]
+    fn test_overflowing_ops() {
+        let max = PgU64::from(u64::MAX);
+        let one = PgU64::from(1u64);
+        let zero = PgU64::from(0u64);
+        assert_eq!(max.overflowing_add(one), (zero, true));
+        assert_eq!(zero.overflowing_sub(one), (max, true));
+        assert_eq!(one.overflowing_mul(max), (max, false));
+        assert_eq!(max.overflowing_div(one), (max, false));
+        assert_eq!(max.overflowing_rem(one), (zero, false));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_ref_ops() {
+        let a = PgU64::from(12u64);
+        let b = PgU64::from(5u64);
+        assert_eq!(&a + &b, PgU64::from(17u64));
+        assert_eq!(&a - b, PgU64::from(7u64));
+        assert_eq!(a * &b, PgU64::from(60u64));
+        assert_eq!(&a / &b, PgU64::from(2u64));
+        assert_eq!(&a % &b, PgU64::from(2u64));
+
+        let mut c = a;
+        c += &b;
+        c %= b;
+        c %= &a;
+        assert_eq!(c, PgU64::from(2u64));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_bitwise_ops() {
+        let a = PgU64::from(0b1100u64);
+        let b = PgU64::from(0b1010u64);
+        assert_eq!(a & b, PgU64::from(0b1000u64));
+        assert_eq!(&a | &b, PgU64::from(0b1110u64));
+        assert_eq!(a ^ &b, PgU64::from(0b0110u64));
+        assert_eq!(!PgU64::from(0u64), PgU64::from(u64::MAX));
+        assert_eq!(!&a, PgU64::from(!0b1100u64));
+        assert_eq!(a << 2, PgU64::from(0b110000u64));
+        assert_eq!(&a >> 2, PgU64::from(0b11u64));
+        assert_eq!(a & 0b0100, PgU64::from(0b0100u64));
+
+        let mut c = a;
+        c |= b;
+        c &= &a;
+        c ^= 0b0001;
+        c <<= 1;
+        c >>= 2;
+        assert_eq!(c, PgU64::from(0b0110u64));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values = vec![PgU64::from(2u64), PgU64::from(3u64), PgU64::from(4u64)];
+        assert_eq!(values.iter().sum::<PgU64>(), PgU64::from(9u64));
+        assert_eq!(values.iter().product::<PgU64>(), PgU64::from(24u64));
+        assert_eq!(values.clone().into_iter().sum::<PgU64>(), PgU64::from(9u64));
+        assert_eq!(
+            values.clone().into_iter().product::<PgU64>(),
+            PgU64::from(24u64)
+        );
+        assert_eq!(
+            Vec::<PgU64>::new().into_iter().sum::<PgU64>(),
+            PgU64::from(0u64)
+        );
+        assert_eq!(
+            Vec::<PgU64>::new().into_iter().product::<PgU64>(),
+            PgU64::from(1u64)
+        );
+
+        assert_eq!(PgU64::checked_sum(&values), Some(PgU64::from(9u64)));
+        assert_eq!(PgU64::checked_product(values), Some(PgU64::from(24u64)));
+
+        let overflowing = [PgU64::from(u64::MAX), PgU64::from(2u64)];
+        assert_eq!(PgU64::checked_sum(overflowing), None);
+        assert_eq!(PgU64::checked_product(overflowing.iter()), None);
+    }
+
+    #[test]
+    fn test_primitive_ops() {
+        let pg_u64 = PgU64::from(12u64);
+        assert_eq!(pg_u64 + 3, PgU64::from(15u64));
+        assert_eq!(pg_u64 - 3, PgU64::from(9u64));
+        assert_eq!(pg_u64 * 3, PgU64::from(36u64));
+        assert_eq!(pg_u64 / 5, PgU64::from(2u64));
+        assert_eq!(pg_u64 % 5, PgU64::from(2u64));
+        assert_eq!(3 + pg_u64, PgU64::from(15u64));
+        assert_eq!(20 - pg_u64, PgU64::from(8u64));
+        assert_eq!(3 * pg_u64, PgU64::from(36u64));
+        assert_eq!(36 / pg_u64, PgU64::from(3u64));
+        assert_eq!(17 % pg_u64, PgU64::from(5u64));
+
+        let mut pg_u64 = PgU64::from(12u64);
+        pg_u64 += 8;
+        pg_u64 -= 4;
+        pg_u64 *= 3;
+        pg_u64 /= 2;
+        pg_u64 %= 7;
+        assert_eq!(pg_u64, PgU64::from(3u64));
+    }
+
+    #[test]
+    fn test_primitive_cmp() {
+        let pg_u64 = PgU64::from(12u64);
+        assert_eq!(pg_u64, 12u64);
+        assert_eq!(12u64, pg_u64);
+        assert_ne!(pg_u64, 13u64);
+        assert!(pg_u64 < 13u64);
+        assert!(pg_u64 > 11u64);
+        assert!(11u64 < pg_u64);
+        assert!(u64::MAX > pg_u64);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{
+            Bounded, CheckedAdd, FromPrimitive, Num, One, Saturating, ToPrimitive, Zero,
+        };
+
+        assert_eq!(PgU64::zero(), PgU64::from(0u64));
+        assert!(PgU64::zero().is_zero());
+        assert_eq!(PgU64::one(), PgU64::from(1u64));
+        assert_eq!(PgU64::min_value(), PgU64::from(u64::MIN));
+        assert_eq!(PgU64::max_value(), PgU64::from(u64::MAX));
+        assert_eq!(
+            <PgU64 as Num>::from_str_radix("ff", 16),
+            Ok(PgU64::from(255u64))
+        );
+        assert_eq!(
+            CheckedAdd::checked_add(&PgU64::max_value(), &PgU64::one()),
+            None
+        );
+        assert_eq!(
+            Saturating::saturating_add(PgU64::max_value(), PgU64::one()),
+            PgU64::max_value()
+        );
+        assert_eq!(PgU64::from(200u64).to_u8(), Some(200u8));
+        assert_eq!(PgU64::max_value().to_u128(), Some(u64::MAX as u128));
+        assert_eq!(PgU64::from_i64(-1), None);
+        assert_eq!(PgU64::from_u64(200), Some(PgU64::from(200u64)));
+        assert_eq!(
+            num_traits::clamp(PgU64::from(200u64), PgU64::zero(), PgU64::from(100u64)),
+            PgU64::from(100u64)
+        );
+    }
+
     #[test]
     fn try_from_bigdecimal() {
         let pg_u64 = PgU64::try_from(BigDecimal::from(126u64)).unwrap();
@@ -85,24 +251,84 @@ mod pg_u64_tests {
         let pg_u64 = PgU64::try_from(BigDecimal::from(-1));
         assert!(pg_u64.is_err());
         let err = pg_u64.unwrap_err();
-        assert_eq!(err, Error::InvalidValue(BigDecimal::from(-1)));
+        assert_eq!(
+            err,
+            Error::Negative {
+                type_name: "PgU64",
+                value: BigDecimal::from(-1)
+            }
+        );
 
         let fractional = BigDecimal::from(3) / BigDecimal::from(2);
         let pg_u64 = PgU64::try_from(fractional.clone());
-        assert_eq!(pg_u64.unwrap_err(), Error::Fractional(fractional));
+        assert_eq!(
+            pg_u64.unwrap_err(),
+            Error::Fractional {
+                type_name: "PgU64",
+                value: fractional
+            }
+        );
 
         let big_decimal = BigDecimal::from(BigInt::from(2).pow(128));
         let pg_u64 = PgU64::try_from(big_decimal.clone());
-        assert_eq!(pg_u64.unwrap_err(), Error::InvalidValue(big_decimal));
+        assert_eq!(
+            pg_u64.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU64",
+                value: big_decimal,
+                max: BigDecimal::from(u64::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_big_decimal() {
+        let pg_u64 = PgU64::from(u64::MAX);
+        assert_eq!(pg_u64.to_big_decimal(), BigDecimal::from(u64::MAX));
+        assert_eq!(BigDecimal::from(pg_u64), BigDecimal::from(u64::MAX));
+        assert_eq!(PgU64::try_from(pg_u64.to_big_decimal()).unwrap(), pg_u64);
     }
 
     #[test]
+    fn try_from_bigdecimal_out_of_range() {
+        let too_large = BigDecimal::from(u64::MAX) + BigDecimal::from(1);
+        let pg_u64 = PgU64::try_from(too_large.clone());
+        assert_eq!(
+            pg_u64.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU64",
+                value: too_large,
+                max: BigDecimal::from(u64::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_bigdecimal_with_scale() {
+        let scaled = BigDecimal::from_str("5.00").unwrap();
+        assert_eq!(PgU64::try_from(scaled).unwrap().to_uint(), 5u64);
+
+        let trailing_zeros = BigDecimal::from_str("120.000").unwrap();
+        assert_eq!(PgU64::try_from(trailing_zeros).unwrap().to_uint(), 120u64);
+
+        let exponent = BigDecimal::from_str("1E+2").unwrap();
+        assert_eq!(PgU64::try_from(exponent).unwrap().to_uint(), 100u64);
+
+        let negative_scale = BigDecimal::new(BigInt::from(12), -1);
+        assert_eq!(PgU64::try_from(negative_scale).unwrap().to_uint(), 120u64);
+
+        let max = BigDecimal::from(u64::MAX).with_scale(2);
+        assert_eq!(PgU64::try_from(max).unwrap().to_uint(), u64::MAX);
+    }
+
+    #[test]
+    #[allow(deprecated)]
     fn test_option_conversion() {
         let somepguint = Some(PgU64::from(123u64));
         let someuint = somepguint.to_option_uint();
         assert_eq!(someuint, Some(123u64));
 
-        let pguint = PgU64::from(123);
+        let pguint = PgU64::from(123u64);
         let someuint = pguint.to_option_uint();
         assert_eq!(someuint, Some(123u64));
 
@@ -110,4 +336,147 @@ mod pg_u64_tests {
         let someuint = pguint.to_option_uint();
         assert_eq!(someuint, None::<u64>);
     }
+
+    #[test]
+    fn test_from_str_radix() {
+        assert_eq!(PgU64::from_str_radix("ff", 16).unwrap(), 255u64);
+        assert_eq!(PgU64::from_str_radix("777", 8).unwrap(), 511u64);
+        assert!(PgU64::from_str_radix("0xff", 16).is_err());
+        assert!(PgU64::from_str_radix("-1", 10).is_err());
+    }
+
+    #[test]
+    fn test_parse_lenient() {
+        assert_eq!(PgU64::parse_lenient("0xFFFF_FFFF").unwrap(), 0xFFFF_FFFFu64);
+        assert_eq!(PgU64::parse_lenient("0o17").unwrap(), 15u64);
+        assert_eq!(PgU64::parse_lenient("0b1010").unwrap(), 10u64);
+        assert_eq!(PgU64::parse_lenient("1_000_000").unwrap(), 1_000_000u64);
+        assert_eq!(PgU64::parse_lenient("1e6").unwrap(), 1_000_000u64);
+        assert_eq!(PgU64::parse_lenient("1.5E3").unwrap(), 1_500u64);
+        assert_eq!(PgU64::parse_lenient("  42\t").unwrap(), 42u64);
+        assert_eq!(PgU64::parse_lenient("+7").unwrap(), 7u64);
+        assert!(matches!(
+            PgU64::parse_lenient("1.5"),
+            Err(Error::Fractional { .. })
+        ));
+        assert!(matches!(
+            PgU64::parse_lenient("-1"),
+            Err(Error::Negative { .. })
+        ));
+        assert!(matches!(
+            PgU64::parse_lenient("1e20"),
+            Err(Error::TooLarge { .. })
+        ));
+        assert_eq!(
+            PgU64::parse_lenient("0xZZ").unwrap_err(),
+            Error::Parse {
+                type_name: "PgU64",
+                input: "0xZZ".to_owned(),
+                kind: std::num::IntErrorKind::InvalidDigit
+            }
+        );
+
+        // The strict `FromStr` keeps rejecting everything but plain decimal digits
+        assert!(PgU64::from_str("1_000").is_err());
+        assert!(PgU64::from_str("0x10").is_err());
+        assert!(PgU64::from_str(" 1").is_err());
+    }
+
+    #[test]
+    fn test_fmt() {
+        let pg_u64 = PgU64::from(255u64);
+        assert_eq!(format!("{:>6}", pg_u64), "   255");
+        assert_eq!(format!("{:<6}|", pg_u64), "255   |");
+        assert_eq!(format!("{:*^7}", pg_u64), "**255**");
+        assert_eq!(format!("{:+}", pg_u64), "+255");
+        assert_eq!(format!("{:06}", pg_u64), "000255");
+        assert_eq!(format!("{:x}", pg_u64), "ff");
+        assert_eq!(format!("{:#X}", pg_u64), "0xFF");
+        assert_eq!(format!("{:o}", pg_u64), "377");
+        assert_eq!(format!("{:#010b}", pg_u64), "0b11111111");
+        assert_eq!(format!("{:e}", pg_u64), "2.55e2");
+        assert_eq!(format!("{:E}", pg_u64), "2.55E2");
+        assert_eq!(format!("{:?}", pg_u64), "255");
+        assert_eq!(format!("{:x?}", pg_u64), "ff");
+        assert_eq!(format!("{:#?}", pg_u64), "255");
+        assert_eq!(format!("{:#?}", Some(pg_u64)), "Some(\n    255,\n)");
+        assert_eq!(format!("{:?}", Some(pg_u64)), "Some(255)");
+    }
+
+    #[test]
+    fn test_primitive_methods() {
+        let pg_u64 = PgU64::from(1000u64);
+        assert_eq!(pg_u64.pow(2), 1_000_000u64);
+        assert_eq!(pg_u64.checked_pow(2), Some(PgU64::from(1_000_000u64)));
+        assert_eq!(pg_u64.checked_pow(7), None);
+        assert_eq!(pg_u64.isqrt(), 31u64);
+        assert_eq!(PgU64::from(u64::MAX).isqrt(), u32::MAX as u64);
+        assert_eq!(pg_u64.ilog2(), 9);
+        assert_eq!(pg_u64.ilog10(), 3);
+        assert_eq!(pg_u64.count_ones(), 1000u64.count_ones());
+        assert_eq!(pg_u64.leading_zeros(), 54);
+        assert_eq!(pg_u64.trailing_zeros(), 3);
+        assert_eq!(PgU64::from(1u64).rotate_right(1), 1u64 << 63);
+        assert_eq!(PgU64::from(1u64 << 63).rotate_left(1), 1u64);
+        assert_eq!(pg_u64.swap_bytes(), 1000u64.swap_bytes());
+        assert!(PgU64::from(1024u64).is_power_of_two());
+        assert!(!pg_u64.is_power_of_two());
+        assert_eq!(pg_u64.next_power_of_two(), 1024u64);
+        assert_eq!(pg_u64.div_ceil(PgU64::from(3u64)), 334u64);
+        assert_eq!(PgU64::from(3u64).abs_diff(PgU64::from(10u64)), 7u64);
+        assert_eq!(
+            PgU64::from(u64::MAX).midpoint(PgU64::from(u64::MAX - 2)),
+            u64::MAX - 1
+        );
+        assert_eq!(PgU64::from(0u64).midpoint(PgU64::from(3u64)), 1u64);
+    }
+
+    #[test]
+    fn test_non_canonical_input() {
+        // Values like these used to be stored as they were and panic when read back
+        for input in ["5.00", "5E0", "0.5E1", "500E-2"] {
+            let pg_u64 = PgU64::try_from(BigDecimal::from_str(input).unwrap()).unwrap();
+            assert_eq!(pg_u64.to_uint(), 5);
+            assert_eq!(pg_u64.try_to_uint(), Ok(5));
+            assert_eq!(pg_u64.to_string(), "5");
+            assert_eq!(u64::from(pg_u64), 5);
+            assert_eq!(pg_u64 + PgU64::from(1u64), 6u64);
+            assert_eq!(pg_u64 * pg_u64, 25u64);
+            assert_eq!(pg_u64, PgU64::from(5u64));
+        }
+        for input in ["18446744073709551616", "1E20", "-1", "-0.5", "1.5"] {
+            assert!(PgU64::try_from(BigDecimal::from_str(input).unwrap()).is_err());
+        }
+        assert_eq!(
+            PgU64::try_from(BigDecimal::from_str("-0").unwrap()).unwrap(),
+            0u64
+        );
+    }
+
+    #[test]
+    fn test_invalid_radix() {
+        // The primitives panic on these
+        for radix in [0, 1, 37, u32::MAX] {
+            assert_eq!(
+                PgU64::from_str_radix("1", radix).unwrap_err(),
+                Error::Parse {
+                    type_name: "PgU64",
+                    input: "1".to_owned(),
+                    kind: std::num::IntErrorKind::InvalidDigit
+                }
+            );
+            assert!(PgU8::from_str_radix("", radix).is_err());
+            assert!(PgU128::from_str_radix("0", radix).is_err());
+            assert!(PgI128::from_str_radix("-1", radix).is_err());
+            #[cfg(feature = "num-traits")]
+            assert_eq!(
+                <PgU64 as num_traits::Num>::from_str_radix("1", radix)
+                    .unwrap_err()
+                    .kind(),
+                &std::num::IntErrorKind::InvalidDigit
+            );
+        }
+        assert_eq!(PgU64::from_str_radix("z", 36).unwrap(), 35u64);
+        assert!(PgU64::from_str_radix("2", 2).is_err());
+    }
 }
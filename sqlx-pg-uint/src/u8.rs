@@ -1,37 +1,12 @@
 use super::*;
-use sqlx_pg_uint_macros::UIntWrapper;
 
-use crate::UIntType;
-
-impl UIntType for PgU8 {
-    type Uint = u8;
-}
-
-impl private::Sealed for PgU8 {}
-
-#[derive(Debug, PartialEq, Eq, Clone, Hash, PartialOrd, Ord, UIntWrapper, sqlx::FromRow)]
 /// PostgreSQL-compatible unsigned 8-bit integer
-pub struct PgU8 {
-    inner: BigDecimal,
-}
-
-impl From<PgU8> for u8 {
-    fn from(value: PgU8) -> Self {
-        value.inner.to_string().parse().unwrap()
-    }
-}
-
-impl From<u8> for PgU8 {
-    fn from(value: u8) -> Self {
-        Self {
-            inner: BigDecimal::from(value),
-        }
-    }
-}
+pub type PgU8 = PgUint<u8>;
 
 #[cfg(test)]
 mod pg_u8_tests {
     use bigdecimal::num_bigint::BigInt;
+    use std::str::FromStr;
 
     use super::*;
 
@@ -39,7 +14,7 @@ mod pg_u8_tests {
     fn test_to_u8() {
         let pg_u8 = PgU8::from(121u8);
         assert_eq!(pg_u8.to_uint(), 121u8);
-        let pg_u8 = PgU8::from(0);
+        let pg_u8 = PgU8::from(0u8);
         assert_eq!(pg_u8.to_uint(), 0u8);
         let pg_u8 = PgU8::from(u8::MAX);
         assert_eq!(pg_u8.to_uint(), u8::MAX);
@@ -72,6 +47,193 @@ mod pg_u8_tests {
         let _ = pg_u8 - pg_u82;
     }
 
+    #[test]
+    fn test_checked_ops() {
+        let max = PgU8::from(u8::MAX);
+        let one = PgU8::from(1u8);
+        let zero = PgU8::from(0u8);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(zero.checked_sub(one), None);
+        assert_eq!(max.checked_mul(PgU8::from(2u8)), None);
+        assert_eq!(one.checked_div(zero), None);
+        assert_eq!(one.checked_rem(zero), None);
+        assert_eq!(one.checked_add(one), Some(PgU8::from(2u8)));
+        assert_eq!(max.checked_div(max), Some(one));
+        assert_eq!(max.checked_rem(max), Some(zero));
+    }
+
+    #[test]
+    fn test_wrapping_ops() {
+        let max = PgU8::from(u8::MAX);
+        let one = PgU8::from(1u8);
+        let zero = PgU8::from(0u8);
+        assert_eq!(max.wrapping_add(one), zero);
+        assert_eq!(zero.wrapping_sub(one), max);
+        assert_eq!(max.wrapping_mul(PgU8::from(2u8)), PgU8::from(u8::MAX - 1));
+        assert_eq!(max.wrapping_div(one), max);
+        assert_eq!(max.wrapping_rem(PgU8::from(2u8)), one);
+    }
+
+    #[test]
+    fn test_saturating_ops() {
+        let max = PgU8::from(u8::MAX);
+        let one = PgU8::from(1u8);
+        let zero = PgU8::from(0u8);
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(zero.saturating_sub(one), zero);
+        assert_eq!(max.saturating_mul(PgU8::from(2u8)), max);
+        assert_eq!(max.saturating_div(one), max);
+    }
+
+    #[test]
+    fn test_overflowing_ops() {
+        let max = PgU8::from(u8::MAX);
+        let one = PgU8::from(1u8);
+        let zero = PgU8::from(0u8);
+        assert_eq!(max.overflowing_add(one), (zero, true));
+        assert_eq!(zero.overflowing_sub(one), (max, true));
+        assert_eq!(one.overflowing_mul(max), (max, false));
+        assert_eq!(max.overflowing_div(one), (max, false));
+        assert_eq!(max.overflowing_rem(one), (zero, false));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_ref_ops() {
+        let a = PgU8::from(12u8);
+        let b = PgU8::from(5u8);
+        assert_eq!(&a + &b, PgU8::from(17u8));
+        assert_eq!(&a - b, PgU8::from(7u8));
+        assert_eq!(a * &b, PgU8::from(60u8));
+        assert_eq!(&a / &b, PgU8::from(2u8));
+        assert_eq!(&a % &b, PgU8::from(2u8));
+
+        let mut c = a;
+        c += &b;
+        c %= b;
+        c %= &a;
+        assert_eq!(c, PgU8::from(2u8));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_bitwise_ops() {
+        let a = PgU8::from(0b1100u8);
+        let b = PgU8::from(0b1010u8);
+        assert_eq!(a & b, PgU8::from(0b1000u8));
+        assert_eq!(&a | &b, PgU8::from(0b1110u8));
+        assert_eq!(a ^ &b, PgU8::from(0b0110u8));
+        assert_eq!(!PgU8::from(0u8), PgU8::from(u8::MAX));
+        assert_eq!(!&a, PgU8::from(!0b1100u8));
+        assert_eq!(a << 2, PgU8::from(0b110000u8));
+        assert_eq!(&a >> 2, PgU8::from(0b11u8));
+        assert_eq!(a & 0b0100, PgU8::from(0b0100u8));
+
+        let mut c = a;
+        c |= b;
+        c &= &a;
+        c ^= 0b0001;
+        c <<= 1;
+        c >>= 2;
+        assert_eq!(c, PgU8::from(0b0110u8));
+    }
+
+    #[test]
+    fn test_sum_product() {
+        let values = vec![PgU8::from(2u8), PgU8::from(3u8), PgU8::from(4u8)];
+        assert_eq!(values.iter().sum::<PgU8>(), PgU8::from(9u8));
+        assert_eq!(values.iter().product::<PgU8>(), PgU8::from(24u8));
+        assert_eq!(values.clone().into_iter().sum::<PgU8>(), PgU8::from(9u8));
+        assert_eq!(
+            values.clone().into_iter().product::<PgU8>(),
+            PgU8::from(24u8)
+        );
+        assert_eq!(
+            Vec::<PgU8>::new().into_iter().sum::<PgU8>(),
+            PgU8::from(0u8)
+        );
+        assert_eq!(
+            Vec::<PgU8>::new().into_iter().product::<PgU8>(),
+            PgU8::from(1u8)
+        );
+
+        assert_eq!(PgU8::checked_sum(&values), Some(PgU8::from(9u8)));
+        assert_eq!(PgU8::checked_product(values), Some(PgU8::from(24u8)));
+
+        let overflowing = [PgU8::from(u8::MAX), PgU8::from(2u8)];
+        assert_eq!(PgU8::checked_sum(overflowing), None);
+        assert_eq!(PgU8::checked_product(overflowing.iter()), None);
+    }
+
+    #[test]
+    fn test_primitive_ops() {
+        let pg_u8 = PgU8::from(12u8);
+        assert_eq!(pg_u8 + 3, PgU8::from(15u8));
+        assert_eq!(pg_u8 - 3, PgU8::from(9u8));
+        assert_eq!(pg_u8 * 3, PgU8::from(36u8));
+        assert_eq!(pg_u8 / 5, PgU8::from(2u8));
+        assert_eq!(pg_u8 % 5, PgU8::from(2u8));
+        assert_eq!(3 + pg_u8, PgU8::from(15u8));
+        assert_eq!(20 - pg_u8, PgU8::from(8u8));
+        assert_eq!(3 * pg_u8, PgU8::from(36u8));
+        assert_eq!(36 / pg_u8, PgU8::from(3u8));
+        assert_eq!(17 % pg_u8, PgU8::from(5u8));
+
+        let mut pg_u8 = PgU8::from(12u8);
+        pg_u8 += 8;
+        pg_u8 -= 4;
+        pg_u8 *= 3;
+        pg_u8 /= 2;
+        pg_u8 %= 7;
+        assert_eq!(pg_u8, PgU8::from(3u8));
+    }
+
+    #[test]
+    fn test_primitive_cmp() {
+        let pg_u8 = PgU8::from(12u8);
+        assert_eq!(pg_u8, 12u8);
+        assert_eq!(12u8, pg_u8);
+        assert_ne!(pg_u8, 13u8);
+        assert!(pg_u8 < 13u8);
+        assert!(pg_u8 > 11u8);
+        assert!(11u8 < pg_u8);
+        assert!(u8::MAX > pg_u8);
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_num_traits() {
+        use num_traits::{
+            Bounded, CheckedAdd, FromPrimitive, Num, One, Saturating, ToPrimitive, Zero,
+        };
+
+        assert_eq!(PgU8::zero(), PgU8::from(0u8));
+        assert!(PgU8::zero().is_zero());
+        assert_eq!(PgU8::one(), PgU8::from(1u8));
+        assert_eq!(PgU8::min_value(), PgU8::from(u8::MIN));
+        assert_eq!(PgU8::max_value(), PgU8::from(u8::MAX));
+        assert_eq!(
+            <PgU8 as Num>::from_str_radix("ff", 16),
+            Ok(PgU8::from(255u8))
+        );
+        assert_eq!(
+            CheckedAdd::checked_add(&PgU8::max_value(), &PgU8::one()),
+            None
+        );
+        assert_eq!(
+            Saturating::saturating_add(PgU8::max_value(), PgU8::one()),
+            PgU8::max_value()
+        );
+        assert_eq!(PgU8::from(200u8).to_u8(), Some(200u8));
+        assert_eq!(PgU8::max_value().to_u128(), Some(u8::MAX as u128));
+        assert_eq!(PgU8::from_i64(-1), None);
+        assert_eq!(PgU8::from_u64(200), Some(PgU8::from(200u8)));
+        assert_eq!(
+            num_traits::clamp(PgU8::from(200u8), PgU8::zero(), PgU8::from(100u8)),
+            PgU8::from(100u8)
+        );
+    }
+
     #[test]
     fn try_from_bigdecimal() {
         let pg_u8 = PgU8::try_from(BigDecimal::from(126u8)).unwrap();
@@ -86,24 +248,84 @@ mod pg_u8_tests {
         let pg_u8 = PgU8::try_from(BigDecimal::from(-1));
         assert!(pg_u8.is_err());
         let err = pg_u8.unwrap_err();
-        assert_eq!(err, Error::InvalidValue(BigDecimal::from(-1)));
+        assert_eq!(
+            err,
+            Error::Negative {
+                type_name: "PgU8",
+                value: BigDecimal::from(-1)
+            }
+        );
 
         let fractional = BigDecimal::from(3) / BigDecimal::from(2);
         let pg_u8 = PgU8::try_from(fractional.clone());
-        assert_eq!(pg_u8.unwrap_err(), Error::Fractional(fractional));
+        assert_eq!(
+            pg_u8.unwrap_err(),
+            Error::Fractional {
+                type_name: "PgU8",
+                value: fractional
+            }
+        );
 
         let big_decimal = BigDecimal::from(BigInt::from(2).pow(128));
         let pg_u8 = PgU8::try_from(big_decimal.clone());
-        assert_eq!(pg_u8.unwrap_err(), Error::InvalidValue(big_decimal));
+        assert_eq!(
+            pg_u8.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU8",
+                value: big_decimal,
+                max: BigDecimal::from(u8::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn test_to_big_decimal() {
+        let pg_u8 = PgU8::from(u8::MAX);
+        assert_eq!(pg_u8.to_big_decimal(), BigDecimal::from(u8::MAX));
+        assert_eq!(BigDecimal::from(pg_u8), BigDecimal::from(u8::MAX));
+        assert_eq!(PgU8::try_from(pg_u8.to_big_decimal()).unwrap(), pg_u8);
+    }
+
+    #[test]
+    fn try_from_bigdecimal_out_of_range() {
+        let too_large = BigDecimal::from(u8::MAX) + BigDecimal::from(1);
+        let pg_u8 = PgU8::try_from(too_large.clone());
+        assert_eq!(
+            pg_u8.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU8",
+                value: too_large,
+                max: BigDecimal::from(u8::MAX)
+            }
+        );
+    }
+
+    #[test]
+    fn try_from_bigdecimal_with_scale() {
+        let scaled = BigDecimal::from_str("5.00").unwrap();
+        assert_eq!(PgU8::try_from(scaled).unwrap().to_uint(), 5u8);
+
+        let trailing_zeros = BigDecimal::from_str("120.000").unwrap();
+        assert_eq!(PgU8::try_from(trailing_zeros).unwrap().to_uint(), 120u8);
+
+        let exponent = BigDecimal::from_str("1E+2").unwrap();
+        assert_eq!(PgU8::try_from(exponent).unwrap().to_uint(), 100u8);
+
+        let negative_scale = BigDecimal::new(BigInt::from(12), -1);
+        assert_eq!(PgU8::try_from(negative_scale).unwrap().to_uint(), 120u8);
+
+        let max = BigDecimal::from(u8::MAX).with_scale(2);
+        assert_eq!(PgU8::try_from(max).unwrap().to_uint(), u8::MAX);
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_option_conversion() {
         let somepguint = Some(PgU8::from(123u8));
         let someuint = somepguint.to_option_uint();
         assert_eq!(someuint, Some(123u8));
 
-        let pguint = PgU8::from(123);
+        let pguint = PgU8::from(123u8);
         let someuint = pguint.to_option_uint();
         assert_eq!(someuint, Some(123u8));
 
@@ -0,0 +1,108 @@
+//! Property tests checking that every way of constructing a `PgUint` type yields the same value for
+//! the same number, so that `Eq`, `Ord` and `Hash` agree with the underlying integer.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::BigDecimal;
+use proptest::prelude::*;
+use sqlx_pg_uint::{PgU128, PgU16, PgU32, PgU64, PgU8};
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Returns `value` as a `BigDecimal` with `scale` trailing zeros after the decimal point.
+fn with_scale(value: u128, scale: u32) -> BigDecimal {
+    BigDecimal::new(
+        BigInt::from(value) * BigInt::from(10u8).pow(scale),
+        scale.into(),
+    )
+}
+
+/// Returns `value` as a `BigDecimal` with as many trailing zeros as possible moved into a positive
+/// exponent, e.g. `1E+3` for 1000.
+fn with_exponent(value: u128) -> BigDecimal {
+    let mut digits = value;
+    let mut exponent = 0i64;
+    while digits != 0 && digits % 10 == 0 {
+        digits /= 10;
+        exponent += 1;
+    }
+    BigDecimal::new(BigInt::from(digits), -exponent)
+}
+
+macro_rules! canonical_tests {
+    ($($module:ident: $pg_uint:ty => $uint:ty),*) => {
+        $(
+            mod $module {
+                use super::*;
+
+                proptest! {
+                    #[test]
+                    fn big_decimal_is_canonical(value: $uint, scale in 0u32..8) {
+                        let expected = <$pg_uint>::from(value);
+                        for big_decimal in [with_scale(value.into(), scale), with_exponent(value.into())] {
+                            let pg_uint = <$pg_uint>::try_from(big_decimal).unwrap();
+                            prop_assert_eq!(pg_uint, expected);
+                            prop_assert_eq!(hash_of(&pg_uint), hash_of(&expected));
+                            prop_assert_eq!(pg_uint.to_string(), value.to_string());
+                            prop_assert_eq!(pg_uint.to_big_decimal(), BigDecimal::from(value));
+                        }
+                    }
+
+                    #[test]
+                    fn strings_are_canonical(value: $uint, zeros in 0usize..8) {
+                        let expected = <$pg_uint>::from(value);
+                        prop_assert_eq!(<$pg_uint>::from_str(&value.to_string()).unwrap(), expected);
+                        let padded = format!("{:0>width$}", value, width = zeros + 1);
+                        prop_assert_eq!(<$pg_uint>::from_str(&padded).unwrap(), expected);
+                        let fractional = format!("{}.{}", value, "0".repeat(zeros));
+                        prop_assert_eq!(<$pg_uint>::parse_lenient(&fractional).unwrap(), expected);
+                        let scientific = format!("{}e0", value);
+                        prop_assert_eq!(<$pg_uint>::parse_lenient(&scientific).unwrap(), expected);
+                    }
+
+                    #[test]
+                    fn comparisons_match_primitive(a: $uint, b: $uint, scale_a in 0u32..4, scale_b in 0u32..4) {
+                        let pg_a = <$pg_uint>::try_from(with_scale(a.into(), scale_a)).unwrap();
+                        let pg_b = <$pg_uint>::try_from(with_scale(b.into(), scale_b)).unwrap();
+                        prop_assert_eq!(pg_a == pg_b, a == b);
+                        prop_assert_eq!(pg_a.cmp(&pg_b), a.cmp(&b));
+                        prop_assert_eq!(pg_a.partial_cmp(&pg_b), a.partial_cmp(&b));
+                        if a == b {
+                            prop_assert_eq!(hash_of(&pg_a), hash_of(&pg_b));
+                        }
+                    }
+                }
+
+                #[cfg(feature = "serde")]
+                proptest! {
+                    #[test]
+                    fn deserialize_is_canonical(value: $uint, zeros in 0usize..8) {
+                        use serde::de::value::{Error, StrDeserializer};
+                        use serde::de::{Deserialize, IntoDeserializer};
+
+                        let input = format!("{}.{}", value, "0".repeat(zeros));
+                        let deserializer: StrDeserializer<'_, Error> = input.as_str().into_deserializer();
+                        let pg_uint = <$pg_uint>::deserialize(deserializer).unwrap();
+                        prop_assert_eq!(pg_uint, <$pg_uint>::from(value));
+                        prop_assert_eq!(hash_of(&pg_uint), hash_of(&<$pg_uint>::from(value)));
+                    }
+                }
+            }
+        )*
+    };
+}
+
+canonical_tests!(
+    pg_u8: PgU8 => u8,
+    pg_u16: PgU16 => u16,
+    pg_u32: PgU32 => u32,
+    pg_u64: PgU64 => u64,
+    pg_u128: PgU128 => u128
+);
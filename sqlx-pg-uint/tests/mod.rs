@@ -4,13 +4,14 @@ mod pg_u128_tests {
     use bigdecimal::BigDecimal;
     use sqlx_pg_uint::{Error, OptionPgUint, PgU128};
 
+    #[allow(unused_imports)]
     use super::*;
 
     #[test]
     fn test_to_u128() {
         let pg_u128 = PgU128::from(12678671u128);
         assert_eq!(pg_u128.to_uint(), 12678671u128);
-        let pg_u128 = PgU128::from(0);
+        let pg_u128 = PgU128::from(0u128);
         assert_eq!(pg_u128.to_uint(), 0u128);
         let pg_u128 = PgU128::from(u128::MAX);
         assert_eq!(pg_u128.to_uint(), u128::MAX);
@@ -57,24 +58,44 @@ mod pg_u128_tests {
         let pg_u128 = PgU128::try_from(BigDecimal::from(-1));
         assert!(pg_u128.is_err());
         let err = pg_u128.unwrap_err();
-        assert_eq!(err, Error::InvalidValue(BigDecimal::from(-1)));
+        assert_eq!(
+            err,
+            Error::Negative {
+                type_name: "PgU128",
+                value: BigDecimal::from(-1)
+            }
+        );
 
         let fractional = BigDecimal::from(3) / BigDecimal::from(2);
         let pg_u128 = PgU128::try_from(fractional.clone());
-        assert_eq!(pg_u128.unwrap_err(), Error::Fractional(fractional));
+        assert_eq!(
+            pg_u128.unwrap_err(),
+            Error::Fractional {
+                type_name: "PgU128",
+                value: fractional
+            }
+        );
 
         let big_decimal = BigDecimal::from(BigInt::from(2).pow(128));
         let pg_u128 = PgU128::try_from(big_decimal.clone());
-        assert_eq!(pg_u128.unwrap_err(), Error::InvalidValue(big_decimal));
+        assert_eq!(
+            pg_u128.unwrap_err(),
+            Error::TooLarge {
+                type_name: "PgU128",
+                value: big_decimal,
+                max: BigDecimal::from(u128::MAX)
+            }
+        );
     }
 
     #[test]
+    #[allow(deprecated)]
     fn test_option_conversion() {
         let somepguint = Some(PgU128::from(123u128));
         let someuint = somepguint.to_option_uint();
         assert_eq!(someuint, Some(123u128));
 
-        let pguint = PgU128::from(123);
+        let pguint = PgU128::from(123u128);
         let someuint = pguint.to_option_uint();
         assert_eq!(someuint, Some(123u128));
 